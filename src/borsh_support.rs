@@ -0,0 +1,43 @@
+use crate::*;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::io::{Read, Result, Write};
+
+impl<Data, Idx> BorshSerialize for IndexTo<Data, Idx>
+where
+    Data: ?Sized,
+    Idx: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.index.serialize(writer)
+    }
+}
+
+impl<Data, Idx> BorshDeserialize for IndexTo<Data, Idx>
+where
+    Data: ?Sized,
+    Idx: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let index = Idx::deserialize_reader(reader)?;
+        Ok(IndexTo::from_index(index))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn borsh_round_trip()
+    {
+        let idx = IndexTo::<str, u32>::from_index(7);
+
+        let bytes = borsh::to_vec(&idx).unwrap();
+        assert_eq!(bytes, 7u32.to_le_bytes());
+
+        let deserialized : IndexTo<str, u32> = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(deserialized, idx);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::*;
+use std::fmt::Display;
+
+/// Error returned when a strongly typed index is out of bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndexError
+{
+    /// The index that was attempted.
+    pub index : usize,
+    /// The length of the collection.
+    pub len : usize,
+}
+
+impl Display for IndexError { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { write!(f, "index {} out of bounds for length {}", self.index, self.len) } }
+impl std::error::Error for IndexError {}
+
+/// Indexing that return a `Result` with an [IndexError] instead of panicking.
+pub trait CheckedIndex
+{
+    type Output : ?Sized;
+    fn checked_index(&self, index : IndexTo<Self::Output>) -> Result<&Self::Output, IndexError>;
+}
+
+impl<T> CheckedIndex for [T]
+{
+    type Output = T;
+    #[inline]
+    fn checked_index(&self, index : IndexTo<T>) -> Result<&T, IndexError> { self.get(index.index()).ok_or(IndexError { index : index.index(), len : self.len() }) }
+}
+
+impl<T> CheckedIndex for Vec<T>
+{
+    type Output = T;
+    #[inline]
+    fn checked_index(&self, index : IndexTo<T>) -> Result<&T, IndexError> { self.as_slice().checked_index(index) }
+}
+
+
+/// A collection with a length.
+pub trait HasLen
+{
+    fn len(&self) -> usize;
+    #[inline]
+    fn is_empty(&self) -> bool { self.len() == 0 }
+}
+
+impl<T> HasLen for [T]   { #[inline] fn len(&self) -> usize { <[T]>::len(self) } }
+impl<T> HasLen for Vec<T> { #[inline] fn len(&self) -> usize { Vec::len(self) } }
+impl    HasLen for str   { #[inline] fn len(&self) -> usize { str::len(self) } }
+
+impl<Data> IndexTo<Data>
+    where
+    Data : ?Sized,
+{
+    /// Check that this index is in bounds for `collection`, only when `debug_assertions` are enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the index is out of bounds, with the [IndexError] message.
+    #[inline]
+    #[track_caller]
+    pub fn debug_assert_valid<C>(self, collection : &C) where C : HasLen + ?Sized
+    {
+        if cfg!(debug_assertions)
+        {
+            let len = collection.len();
+            if self.index >= len { panic!("{}", IndexError { index : self.index, len }); }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn checked_index()
+    {
+        let v = vec![1, 2, 3];
+        assert_eq!(v.checked_index(v.index_to(1)), Ok(&2));
+        assert_eq!(v.as_slice().checked_index(v.index_to(2)), Ok(&3));
+
+        let err = v.checked_index(IndexTo::from_index(7)).unwrap_err();
+        assert_eq!(err.index, 7);
+        assert_eq!(err.len, 3);
+        assert_eq!(err.to_string(), "index 7 out of bounds for length 3");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn debug_assert_valid()
+    {
+        let v = vec![1, 2, 3];
+        v.index_to(2).debug_assert_valid(&v);
+        IndexTo::<u8>::from_index(0).debug_assert_valid("a");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "index 3 out of bounds for length 3")]
+    fn debug_assert_valid_out_of_bounds()
+    {
+        let v = [1, 2, 3];
+        IndexTo::<i32>::from_index(3).debug_assert_valid(v.as_slice());
+    }
+}
@@ -0,0 +1,165 @@
+use crate::*;
+
+/// A strongly typed index that also remember the generation of the slot it was created for.
+///
+/// Used by [GenVec] to detect stale index : once a slot is removed and reused,
+/// the old index no longer match the generation of the slot.
+pub struct GenIndex<Data>
+    where
+    Data : ?Sized,
+{
+    index : IndexTo<Data>,
+    generation : u32,
+}
+
+impl<Data> GenIndex<Data>
+    where
+    Data : ?Sized,
+{
+    #[inline]
+    pub const fn new(index : IndexTo<Data>, generation : u32) -> Self { Self { index, generation } }
+    /// The slot of this index, without the generation check.
+    #[inline]
+    pub const fn slot(self) -> IndexTo<Data> { self.index }
+    #[inline]
+    pub const fn generation(self) -> u32 { self.generation }
+}
+
+impl<Data : ?Sized> Hash       for GenIndex<Data> { #[inline] fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.index.hash(state); self.generation.hash(state); } }
+impl<Data : ?Sized> Clone      for GenIndex<Data> { #[inline] fn clone(&self) -> Self { *self } }
+impl<Data : ?Sized> Copy       for GenIndex<Data> {}
+impl<Data : ?Sized> Debug      for GenIndex<Data> { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { write!(f, "{:?}@{}", self.index, self.generation) } }
+impl<Data : ?Sized> Eq         for GenIndex<Data> {}
+impl<Data : ?Sized> PartialEq  for GenIndex<Data> { #[inline] fn eq(&self, other: &Self) -> bool { self.index == other.index && self.generation == other.generation } }
+
+struct GenSlot<T>
+{
+    generation : u32,
+    value : Option<T>,
+}
+
+/// A collection with reusable slots, indexed by [GenIndex].
+///
+/// Accessing an element with an index whose slot was removed (and maybe reused since) return `None`.
+pub struct GenVec<T>
+{
+    slots : Vec<GenSlot<T>>,
+    free : Vec<usize>,
+    len : usize,
+}
+
+impl<T> GenVec<T>
+{
+    #[inline]
+    pub const fn new() -> Self { Self { slots : Vec::new(), free : Vec::new(), len : 0 } }
+
+    #[inline]
+    pub const fn len(&self) -> usize { self.len }
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Insert a value, reusing a free slot if there is one.
+    pub fn insert(&mut self, value : T) -> GenIndex<T>
+    {
+        self.len += 1;
+        match self.free.pop()
+        {
+            Some(slot) =>
+            {
+                let s = &mut self.slots[slot];
+                s.value = Some(value);
+                GenIndex::new(IndexTo::from_index(slot), s.generation)
+            },
+            None =>
+            {
+                self.slots.push(GenSlot { generation : 0, value : Some(value) });
+                GenIndex::new(IndexTo::from_index(self.slots.len() - 1), 0)
+            },
+        }
+    }
+
+    /// Remove the value at this index. Return `None` if the index is stale.
+    ///
+    /// Every index previously given for this slot become stale.
+    pub fn remove(&mut self, index : GenIndex<T>) -> Option<T>
+    {
+        let slot = self.slots.get_mut(index.slot().index())?;
+        if slot.generation != index.generation { return None; }
+
+        let value = slot.value.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(index.slot().index());
+        self.len -= 1;
+        Some(value)
+    }
+
+    #[inline]
+    pub fn contains(&self, index : GenIndex<T>) -> bool { self.get(index).is_some() }
+
+    /// Return `None` if the index is stale.
+    pub fn get(&self, index : GenIndex<T>) -> Option<&T>
+    {
+        let slot = self.slots.get(index.slot().index())?;
+        if slot.generation == index.generation { slot.value.as_ref() } else { None }
+    }
+
+    /// Return `None` if the index is stale.
+    pub fn get_mut(&mut self, index : GenIndex<T>) -> Option<&mut T>
+    {
+        let slot = self.slots.get_mut(index.slot().index())?;
+        if slot.generation == index.generation { slot.value.as_mut() } else { None }
+    }
+}
+
+impl<T> Default for GenVec<T> { #[inline] fn default() -> Self { Self::new() } }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn insert_and_get()
+    {
+        let mut v = GenVec::new();
+        let a = v.insert("a");
+        let b = v.insert("b");
+
+        assert_eq!(v.get(a), Some(&"a"));
+        assert_eq!(v.get(b), Some(&"b"));
+        assert_eq!(v.len(), 2);
+
+        *v.get_mut(b).unwrap() = "c";
+        assert_eq!(v.get(b), Some(&"c"));
+    }
+
+    #[test]
+    fn remove()
+    {
+        let mut v = GenVec::new();
+        let a = v.insert(1);
+
+        assert_eq!(v.remove(a), Some(1));
+        assert_eq!(v.remove(a), None);
+        assert_eq!(v.get(a), None);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn stale_index_after_reinsert()
+    {
+        let mut v = GenVec::new();
+        let old = v.insert(1);
+        v.remove(old);
+
+        let new = v.insert(2);
+        assert_eq!(old.slot(), new.slot());
+        assert_ne!(old, new);
+
+        assert_eq!(v.get(old), None);
+        assert_eq!(v.get_mut(old), None);
+        assert!(!v.contains(old));
+        assert_eq!(v.remove(old), None);
+        assert_eq!(v.get(new), Some(&2));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::*;
+
+/// A strongly typed 2D index `(row, col)` into a flattened grid of a given width, stored row by row.
+pub struct GridIndex<Data>
+    where
+    Data : ?Sized,
+{
+    row : usize,
+    col : usize,
+    width : usize,
+    index_data : PhantomData<fn() -> Data>,
+}
+
+impl<Data> GridIndex<Data>
+    where
+    Data : ?Sized,
+{
+    #[inline]
+    pub const fn new(row : usize, col : usize, width : usize) -> Self { Self { row, col, width, index_data : PhantomData } }
+
+    /// Convert a flat index back to grid coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is `0`.
+    #[inline]
+    pub const fn from_flat(index : IndexTo<Data>, width : usize) -> Self { Self::new(index.index / width, index.index % width, width) }
+
+    #[inline]
+    pub const fn row(self) -> usize { self.row }
+    #[inline]
+    pub const fn col(self) -> usize { self.col }
+    #[inline]
+    pub const fn width(self) -> usize { self.width }
+
+    /// The flat index `row * width + col`.
+    ///
+    /// Don't check that `col < width`, see [GridIndex::checked_flatten].
+    #[inline]
+    pub const fn flatten(self) -> IndexTo<Data> { IndexTo::from_index(self.row * self.width + self.col) }
+
+    /// The flat index `row * width + col`, or `None` if `col >= width` or on overflow.
+    #[inline]
+    pub const fn checked_flatten(self) -> Option<IndexTo<Data>>
+    {
+        if self.col >= self.width { return None; }
+        match self.row.checked_mul(self.width)
+        {
+            Some(start) => match start.checked_add(self.col)
+            {
+                Some(index) => Some(IndexTo::from_index(index)),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the flat index is out of bounds.
+    #[inline]
+    pub fn get(self, grid : &[Data]) -> &Data where Data : Sized { &grid[self.flatten()] }
+    /// # Panics
+    ///
+    /// Panics if the flat index is out of bounds.
+    #[inline]
+    pub fn get_mut(self, grid : &mut [Data]) -> &mut Data where Data : Sized { &mut grid[self.flatten()] }
+}
+
+impl<Data : ?Sized> Hash       for GridIndex<Data> { #[inline] fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.row.hash(state); self.col.hash(state); self.width.hash(state); } }
+impl<Data : ?Sized> Clone      for GridIndex<Data> { #[inline] fn clone(&self) -> Self { *self } }
+impl<Data : ?Sized> Copy       for GridIndex<Data> {}
+impl<Data : ?Sized> Debug      for GridIndex<Data> { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { fmt_type_name(f, std::any::type_name::<Data>())?; write!(f, "#({}, {})", self.row, self.col) } }
+impl<Data : ?Sized> Eq         for GridIndex<Data> {}
+impl<Data : ?Sized> PartialEq  for GridIndex<Data> { #[inline] fn eq(&self, other: &Self) -> bool { self.row == other.row && self.col == other.col && self.width == other.width } }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn flatten_and_back()
+    {
+        // 3 x 2 grid
+        let grid = vec!['a', 'b', 'c', 'd', 'e', 'f'];
+
+        let idx = GridIndex::<char>::new(1, 2, 3);
+        assert_eq!(idx.flatten(), grid.index_to(5));
+        assert_eq!(idx.get(&grid), &'f');
+        assert_eq!(GridIndex::from_flat(grid.index_to(5), 3), idx);
+
+        for flat in 0..grid.len()
+        {
+            let idx = GridIndex::<char>::from_flat(grid.index_to(flat), 3);
+            assert_eq!(idx.row(), flat / 3);
+            assert_eq!(idx.col(), flat % 3);
+            assert_eq!(idx.flatten().index(), flat);
+        }
+    }
+
+    #[test]
+    fn checked_flatten()
+    {
+        assert_eq!(GridIndex::<char>::new(1, 2, 3).checked_flatten(), Some(IndexTo::from_index(5)));
+        assert_eq!(GridIndex::<char>::new(1, 3, 3).checked_flatten(), None);
+        assert_eq!(GridIndex::<char>::new(0, 0, 0).checked_flatten(), None);
+        assert_eq!(GridIndex::<char>::new(usize::MAX, 0, 2).checked_flatten(), None);
+    }
+
+    #[test]
+    fn get_mut()
+    {
+        let mut grid = [0; 4];
+        *GridIndex::<i32>::new(1, 0, 2).get_mut(&mut grid) = 7;
+        assert_eq!(grid, [0, 0, 7, 0]);
+    }
+}
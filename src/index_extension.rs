@@ -1,9 +1,47 @@
 use crate::*;
+use std::ops::{Range, RangeInclusive, RangeTo};
 
 /// Trait for marking index.
 /// 
 /// Allow to do `index.get(&collection)` or `index.get_mut(&mut collection)`.
-pub trait IndexLike : Copy
+/// 
+/// Also implemented for range, to get a sub slice : `(1..3).get(&vec)`.
+///
+/// # Indexing through a reference
+///
+/// `Index` is implemented for the collections (`Vec<T>`, `[T]`...), not for reference to them (`&Vec<T>`, `&[T]`...) :
+/// a blanket `impl<C, I> Index<I> for &C` is not allowed by the orphan rules,
+/// and indexing a reference stop at the first autoderef step with an `Index` impl,
+/// so an impl on `&[T]` would break `slice_ref[0]`.
+///
+/// Indexing a reference still work through autoderef, and generic code should take the collection by reference :
+///
+/// ```
+/// use typed_index::*;
+/// use std::ops::Index;
+///
+/// fn get_at<C>(collection : &C, index : IndexTo<i32>) -> i32 where C : Index<IndexTo<i32>, Output = i32> + ?Sized { collection[index] }
+///
+/// let v = vec![1, 2, 3];
+/// let idx = v.index_to(1);
+/// let v_ref = &v;
+///
+/// assert_eq!(v_ref[idx], 2);
+/// assert_eq!(idx.get(v_ref), &2);
+/// assert_eq!(get_at(v_ref, idx), 2);
+/// assert_eq!(get_at(v.as_slice(), idx), 2);
+/// ```
+///
+/// A reference to a reference is not supported : `idx.get(&&v)` don't compile, use `idx.get(&v)` instead.
+///
+/// ```compile_fail
+/// use typed_index::*;
+///
+/// let v = vec![1, 2, 3];
+/// let idx = v.index_to(1);
+/// idx.get(&&v);
+/// ```
+pub trait IndexLike : Sized
 {
     fn get<T>(self, inside : &T) -> &T::Output where T : Index<Self> { inside.index(self) }
     fn get_mut<T>(self, inside : &mut T) -> &mut T::Output where T : IndexMut<Self> { inside.index_mut(self) }
@@ -11,4 +49,89 @@ pub trait IndexLike : Copy
 
 impl IndexLike for usize {}
 impl IndexLike for isize {}
-impl<Data, Idx>  IndexLike for IndexTo<Data, Idx> where Self : Copy, Data : ?Sized {}
\ No newline at end of file
+impl IndexLike for Range<usize> {}
+impl IndexLike for RangeInclusive<usize> {}
+impl IndexLike for RangeTo<usize> {}
+impl<Data, Idx>  IndexLike for IndexTo<Data, Idx> where Self : Copy, Data : ?Sized {}
+
+/// Access an array element with a strongly typed index, in a const context.
+///
+/// [IndexLike::get] go through the `Index` trait, and trait method can't be called in a const context yet.
+///
+/// # Panics
+///
+/// Panics if the index is out of bounds (at compile time when evaluated in a const context).
+#[inline]
+pub const fn index_array<T, const N : usize>(arr : &[T; N], idx : IndexTo<T>) -> &T { &arr[idx.index] }
+
+/// Trait implemented by strongly typed index, to expose the indexed `Data` type and the inner `Idx` type.
+///
+/// Allow to write generic code over any typed index while still constraining the index type : `where I : IndexMarker<Idx = u32>`.
+pub trait IndexMarker
+{
+    /// The type being indexed.
+    type Data : ?Sized;
+    /// The inner index type.
+    type Idx;
+
+    /// `true` if the inner index is a zero sized type.
+    const IDX_IS_ZST : bool = std::mem::size_of::<Self::Idx>() == 0;
+
+    fn as_index(&self) -> &Self::Idx;
+    fn into_index(self) -> Self::Idx;
+}
+
+impl<Data, Idx> IndexMarker for IndexTo<Data, Idx> where Data : ?Sized
+{
+    type Data = Data;
+    type Idx = Idx;
+
+    #[inline]
+    fn as_index(&self) -> &Self::Idx { &self.index }
+    #[inline]
+    fn into_index(self) -> Self::Idx { self.index }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    static PRIMES : [u32; 4] = [2, 3, 5, 7];
+    const THIRD_PRIME : &u32 = index_array(&PRIMES, IndexTo::from_index(2));
+
+    #[test]
+    fn const_index_array()
+    {
+        assert_eq!(*THIRD_PRIME, 5);
+        assert_eq!(index_array(&PRIMES, PRIMES.index_to(3)), &7);
+    }
+
+    #[test]
+    fn range_index_like()
+    {
+        let mut v = vec![1, 2, 3, 4];
+
+        assert_eq!((1..3).get(&v), &[2, 3]);
+        assert_eq!((1..=3).get(&v), &[2, 3, 4]);
+        assert_eq!((..2).get(&v), &[1, 2]);
+
+        (2..4).get_mut(&mut v).fill(0);
+        assert_eq!(v, [1, 2, 0, 0]);
+    }
+
+    fn describe<I>(index : I) -> String where I : IndexMarker<Idx = u32>
+    {
+        format!("{}:{}", std::any::type_name::<I::Data>(), index.into_index())
+    }
+
+    #[test]
+    fn index_marker()
+    {
+        assert_eq!(describe(IndexTo::<str, u32>::from_index(3)), "str:3");
+        assert_eq!(IndexMarker::as_index(&IndexTo::<str, u32>::from_index(3)), &3);
+
+        const { assert!(!<IndexTo<str, u32> as IndexMarker>::IDX_IS_ZST) };
+        const { assert!(<IndexTo<str, ()> as IndexMarker>::IDX_IS_ZST) };
+    }
+}
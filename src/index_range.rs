@@ -0,0 +1,179 @@
+use crate::*;
+
+/// A half-open range `[start, end)` of strongly typed index.
+///
+/// Iterating over it yield every typed index of the range. The range is empty if `end <= start`.
+pub struct IndexRange<Data, Idx=usize>
+    where
+    Data : ?Sized,
+{
+    start : IndexTo<Data, Idx>,
+    end   : IndexTo<Data, Idx>,
+}
+
+impl<Data, Idx> IndexRange<Data, Idx>
+    where
+    Data : ?Sized,
+{
+    #[inline]
+    pub const fn new(start : IndexTo<Data, Idx>, end : IndexTo<Data, Idx>) -> Self { Self { start, end } }
+    #[inline]
+    pub const fn start(&self) -> &IndexTo<Data, Idx> { &self.start }
+    #[inline]
+    pub const fn end(&self) -> &IndexTo<Data, Idx> { &self.end }
+}
+
+impl<Data> IndexRange<Data, usize>
+    where
+    Data : ?Sized,
+{
+    /// Yield `start, start + step, start + 2 * step...` up to `end` (exclusive).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `0`, like [Iterator::step_by].
+    #[inline]
+    pub fn step_by(self, step : usize) -> impl Iterator<Item = IndexTo<Data>> { Iterator::step_by(self, step) }
+}
+
+impl<Data> IndexTo<Data, usize>
+    where
+    Data : ?Sized,
+{
+    /// The half-open range `[self, end)` of typed index. Empty if `end <= self`.
+    #[inline]
+    pub const fn range_to(self, end : Self) -> IndexRange<Data> { IndexRange::new(self, end) }
+}
+
+/// Iterate over the typed index of a linked structure, starting at `start` and following `step` until it return `None`.
+///
+/// Like [std::iter::successors], but keep the index type.
+#[inline]
+pub fn follow<Data, F>(start : IndexTo<Data>, mut step : F) -> impl Iterator<Item = IndexTo<Data>>
+    where
+    Data : ?Sized,
+    F : FnMut(IndexTo<Data>) -> Option<IndexTo<Data>>,
+{
+    std::iter::successors(Some(start), move |idx| step(*idx))
+}
+
+impl<Data : ?Sized, Idx> Clone for IndexRange<Data, Idx> where Idx : Clone { #[inline] fn clone(&self) -> Self { Self::new(self.start.clone(), self.end.clone()) } }
+impl<Data : ?Sized, Idx> Debug for IndexRange<Data, Idx> where Idx : Debug { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { write!(f, "{:?}..{:?}", self.start, self.end) } }
+
+impl<Data> Iterator for IndexRange<Data, usize>
+    where
+    Data : ?Sized,
+{
+    type Item = IndexTo<Data>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.start < self.end
+        {
+            let idx = self.start;
+            self.start.index += 1;
+            Some(idx)
+        } else { None }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let len = self.end.index.saturating_sub(self.start.index);
+        (len, Some(len))
+    }
+}
+
+impl<Data> DoubleEndedIterator for IndexRange<Data, usize>
+    where
+    Data : ?Sized,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item>
+    {
+        if self.start < self.end
+        {
+            self.end.index -= 1;
+            Some(self.end)
+        } else { None }
+    }
+}
+
+impl<Data> ExactSizeIterator for IndexRange<Data, usize> where Data : ?Sized {}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn range_to()
+    {
+        let v = vec!['a', 'b', 'c', 'd'];
+        let range = v.index_to(1).range_to(v.index_to(3));
+
+        assert_eq!(range.len(), 2);
+        assert_eq!(range.clone().collect::<Vec<_>>(), [v.index_to(1), v.index_to(2)]);
+        assert_eq!(range.map(|i| v[i]).collect::<String>(), "bc");
+        assert_eq!(v.index_to(0).range_to(v.index_to(4)).rev().map(|i| v[i]).collect::<String>(), "dcba");
+    }
+
+    #[test]
+    fn follow_linked_list()
+    {
+        struct Node { value : char, next : Option<IndexTo<Node>> }
+
+        let nodes = vec!
+        [
+            Node { value : 'c', next : None },
+            Node { value : 'a', next : Some(IndexTo::from_index(2)) },
+            Node { value : 'b', next : Some(IndexTo::from_index(0)) },
+        ];
+
+        let visited : Vec<_> = follow(nodes.index_to(1), |i| nodes[i].next).collect();
+        assert_eq!(visited, [nodes.index_to(1), nodes.index_to(2), nodes.index_to(0)]);
+        assert_eq!(visited.iter().map(|i| nodes[*i].value).collect::<String>(), "abc");
+
+        assert_eq!(follow(nodes.index_to(0), |i| nodes[i].next).count(), 1);
+    }
+
+    #[test]
+    fn step_by()
+    {
+        let v = vec![0, 10, 20, 30, 40];
+        let start = v.index_to(0);
+
+        assert_eq!(start.range_to(v.index_to(3)).step_by(1).map(|i| v[i]).collect::<Vec<_>>(), [0, 10, 20]);
+        assert_eq!(start.range_to(v.index_to(4)).step_by(2).map(|i| v[i]).collect::<Vec<_>>(), [0, 20]);
+        assert_eq!(start.range_to(v.index_to(5)).step_by(2).map(|i| v[i]).collect::<Vec<_>>(), [0, 20, 40]);
+        assert_eq!(v.index_to(1).range_to(v.index_to(5)).step_by(3).collect::<Vec<_>>(), [v.index_to(1), v.index_to(4)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn step_by_zero()
+    {
+        let start = IndexTo::<i32>::from_index(0);
+        let _ = start.range_to(IndexTo::from_index(3)).step_by(0);
+    }
+
+    #[test]
+    fn range_to_empty()
+    {
+        let start = IndexTo::<char>::from_index(2);
+        assert_eq!(start.range_to(start).count(), 0);
+    }
+
+    #[test]
+    fn range_to_reversed()
+    {
+        let start = IndexTo::<char>::from_index(3);
+        let end = IndexTo::<char>::from_index(1);
+
+        let mut range = start.range_to(end);
+        assert_eq!(range.len(), 0);
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::*;
+
+/// A strongly typed index that can only be used to mutate what it is indexing.
+///
+/// Allow an API to require an index destined to mutation. Created with [IndexTo::for_mut].
+///
+/// Only `get_mut` is available, not `get` :
+///
+/// ```compile_fail
+/// use typed_index::*;
+///
+/// let v = vec![1, 2, 3];
+/// let idx = v.index_to(0).for_mut();
+/// idx.get(&v);
+/// ```
+pub struct IndexToMut<Data>
+    where
+    Data : ?Sized,
+{
+    index : IndexTo<Data>,
+}
+
+impl<Data> IndexToMut<Data>
+    where
+    Data : ?Sized,
+{
+    #[inline]
+    pub const fn index(self) -> usize { self.index.index }
+
+    #[inline]
+    pub fn get_mut<T>(self, inside : &mut T) -> &mut T::Output where T : IndexMut<IndexTo<Data>> + ?Sized { inside.index_mut(self.index) }
+}
+
+impl<Data> IndexTo<Data>
+    where
+    Data : ?Sized,
+{
+    /// Mark this index as destined to mutation.
+    #[inline]
+    pub const fn for_mut(self) -> IndexToMut<Data> { IndexToMut { index : self } }
+}
+
+impl<Data : ?Sized> Hash       for IndexToMut<Data> { #[inline] fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.index.hash(state); } }
+impl<Data : ?Sized> Clone      for IndexToMut<Data> { #[inline] fn clone(&self) -> Self { *self } }
+impl<Data : ?Sized> Copy       for IndexToMut<Data> {}
+impl<Data : ?Sized> Debug      for IndexToMut<Data> { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { write!(f, "mut {:?}", self.index) } }
+impl<Data : ?Sized> Eq         for IndexToMut<Data> {}
+impl<Data : ?Sized> PartialEq  for IndexToMut<Data> { #[inline] fn eq(&self, other: &Self) -> bool { self.index == other.index } }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn set<T>(v : &mut [T], idx : IndexToMut<T>, value : T) { *idx.get_mut(v) = value; }
+
+    #[test]
+    fn index_to_mut()
+    {
+        let mut v = vec![1, 2, 3];
+        let idx = v.index_to(1).for_mut();
+
+        *idx.get_mut(&mut v) = 20;
+        set(&mut v, idx, 30);
+        assert_eq!(v, [1, 30, 3]);
+        assert_eq!(idx.index(), 1);
+    }
+}
@@ -2,11 +2,21 @@
 //! 
 //! Also define a `10usize.get(&myVec)` and `IndexLike.getMut(Inside)` to access value from an index using the [IndexLike] trait.
 //! 
-//! Provides optional support for [Serde](https://docs.rs/serde/latest/serde/) (serialization / deserialization) when the "serde" feature is enabled.
+//! Provides optional support for [Serde](https://docs.rs/serde/latest/serde/) (serialization / deserialization) when the "serde" feature is enabled. Index are serialized as their inner index, or as `{ "idx": n }` with `#[serde(with = "typed_index::serde_named")]`.
+//! 
+//! Provides optional support for [rkyv](https://docs.rs/rkyv/latest/rkyv/) (zero-copy deserialization) when the "rkyv" feature is enabled.
+//! 
+//! Provides optional support for [Borsh](https://docs.rs/borsh/latest/borsh/) (binary serialization) when the "borsh" feature is enabled.
+//! 
+//! Provides optional support for [schemars](https://docs.rs/schemars/latest/schemars/) (JSON Schema generation) when the "schemars" feature is enabled.
+//! 
+//! Provides optional support for [zerocopy](https://docs.rs/zerocopy/0.7/zerocopy/) (`FromBytes`, `AsBytes`, `Unaligned`) when the "zerocopy" feature is enabled.
+//! 
+//! The `Debug` output of an index contains the full path of the indexed type (`my_crate::module::Node#3`). Enable the "short-type-names" feature to remove the module path (`Node#3`).
 //! 
 //! ```rust
 //! use typed_index::*;
-//! use std::ops::Index;
+//! use std::ops::{Index, IndexMut};
 //! 
 //! struct IntAndBool
 //! {
@@ -80,20 +90,75 @@ use std::ops::{Index, IndexMut};
 #[cfg(feature = "serde")]
 pub(crate) mod serde_support;
 #[cfg(feature = "serde")]
-pub(crate) use serde_support::*;
+pub mod serde_named;
+
+#[cfg(feature = "rkyv")]
+mod rkyv_support;
+#[cfg(feature = "rkyv")]
+pub use rkyv_support::*;
+
+#[cfg(feature = "borsh")]
+mod borsh_support;
+
+#[cfg(feature = "schemars")]
+mod schemars_support;
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy_support;
 
 mod std_impl;
 
 mod index_extension;
 pub use index_extension::*;
 
+mod slice_extension;
+pub use slice_extension::*;
+
+mod vec_extension;
+pub use vec_extension::*;
+
+mod str_extension;
+pub use str_extension::*;
+
+mod map_extension;
+pub use map_extension::*;
+
+mod typed_vec;
+pub use typed_vec::*;
+
+mod gen_index;
+pub use gen_index::*;
+
+mod index_range;
+pub use index_range::*;
+
+mod grid_index;
+pub use grid_index::*;
+
+mod ordered_index;
+pub use ordered_index::*;
+
+mod checked_index;
+pub use checked_index::*;
+
+mod index_to_mut;
+pub use index_to_mut::*;
+
+mod nullable_index;
+pub use nullable_index::*;
+
 /// A strongly typed index that know what it is indexing 
+/// 
+/// `IndexTo<Data, Idx>` have the same layout as `Idx` (`repr(transparent)`).
+#[repr(transparent)]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromZeroes, zerocopy::FromBytes, zerocopy::AsBytes, zerocopy::Unaligned))]
 pub struct IndexTo<Data, Idx=usize> 
     where
     Data : ?Sized, 
 {
     index : Idx,
-    index_data  : PhantomData<Data>,
+    // `fn() -> Data` so that `Send`/`Sync` only depend on `Idx`, and not on `Data`
+    index_data  : PhantomData<fn() -> Data>,
 }
 
 impl<Data, Idx> IndexTo<Data, Idx>
@@ -104,26 +169,192 @@ impl<Data, Idx> IndexTo<Data, Idx>
     pub const fn from_index(index : Idx) -> Self { Self { index, index_data: PhantomData }}
     #[inline]
     pub const fn index(self) -> Idx where Idx : Copy { self.index }
+    /// Borrow the inner index, even when `Idx` is not `Copy`.
+    #[inline]
+    pub const fn as_index(&self) -> &Idx { &self.index }
+    /// Move the inner index out, even when `Idx` is not `Copy`.
+    #[inline]
+    pub fn into_index(self) -> Idx { self.index }
+
+    /// Strip the type marker to pass the index across an FFI boundary. Same as [IndexTo::into_index].
+    ///
+    /// Because `IndexTo` is `repr(transparent)`, an array of typed index can also be passed as an array of `Idx`.
+    #[inline]
+    pub fn into_raw(self) -> Idx { self.index }
+    /// Restore a typed index from its raw form, coming from an FFI boundary.
+    ///
+    /// # Safety
+    ///
+    /// The caller asserts that this index is valid for a `Data` collection.
+    #[inline]
+    pub const unsafe fn from_raw(raw : Idx) -> Self { Self::from_index(raw) }
     #[inline]
     pub fn set_index(&mut self, index : Idx) -> &mut Self { self.index = index; self }
     #[inline]
     pub fn with_index(mut self, index : Idx) -> Self { self.set_index(index); self }
+
+    /// Erase the `Data` type, keeping its [TypeId](std::any::TypeId) next to the index.
+    ///
+    /// Useful as a key for map that mix index of different `Data`: two index at the same position but with a different `Data` will never collide.
+    /// (`TypeId` require `Data : 'static`)
+    #[inline]
+    pub fn erased(self) -> (std::any::TypeId, Idx) where Data : 'static, Idx : Copy { (std::any::TypeId::of::<Data>(), self.index) }
+
+    /// The index as an `usize`.
+    ///
+    /// `Into<usize>` depend on the platform : it is only implemented for type that fit in an `usize` on every platform (`u8`, `u16`, `usize`...), not for `u32`.
+    /// Use [IndexTo::try_as_usize] for the other.
+    #[inline]
+    pub fn as_usize(self) -> usize where Idx : Into<usize> { self.index.into() }
+    /// The index as an `usize`, or `None` if it doesn't fit in an `usize`.
+    #[inline]
+    pub fn try_as_usize(self) -> Option<usize> where Idx : TryInto<usize> { self.index.try_into().ok() }
+
+    /// Compare only the inner index of two typed index, whatever their `Data` is.
+    #[inline]
+    pub fn same_index<Other : ?Sized>(&self, other : &IndexTo<Other, Idx>) -> bool where Idx : PartialEq { self.index == other.index }
+
+    /// Compare the position of two index of the same `Data`, even if they are stored with a different `Idx` type.
+    ///
+    /// Positions are compared as `u128`, or as `i128` if one of them is negative, so equal positions are always equal whatever their sign and width.
+    #[inline]
+    pub fn eq_pos<OtherIdx>(&self, other : &IndexTo<Data, OtherIdx>) -> bool
+        where
+        Idx : Copy + TryInto<u128> + TryInto<i128>,
+        OtherIdx : Copy + TryInto<u128> + TryInto<i128>,
+    {
+        match (TryInto::<u128>::try_into(self.index), TryInto::<u128>::try_into(other.index))
+        {
+            (Ok(a), Ok(b)) => a == b,
+            // At least one is negative, or doesn't fit in an `u128`
+            _ => match (TryInto::<i128>::try_into(self.index), TryInto::<i128>::try_into(other.index))
+            {
+                (Ok(a), Ok(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+
+    /// Reinterpret a reference to an index as a reference to a typed index, without copy.
+    #[inline]
+    pub const fn from_ref(index : &Idx) -> &Self
+    {
+        // SAFETY: `IndexTo` is `repr(transparent)` over `Idx`
+        unsafe { &*(index as *const Idx as *const Self) }
+    }
+    /// Reinterpret a slice of index as a slice of typed index, without copy.
+    #[inline]
+    pub const fn from_slice(indices : &[Idx]) -> &[Self]
+    {
+        // SAFETY: `IndexTo` is `repr(transparent)` over `Idx`
+        unsafe { std::slice::from_raw_parts(indices.as_ptr() as *const Self, indices.len()) }
+    }
+    /// Reinterpret a slice of typed index as a slice of index, without copy.
+    #[inline]
+    pub const fn as_slice_of_indices(indices : &[Self]) -> &[Idx]
+    {
+        // SAFETY: `IndexTo` is `repr(transparent)` over `Idx`
+        unsafe { std::slice::from_raw_parts(indices.as_ptr() as *const Idx, indices.len()) }
+    }
+}
+
+impl<Data> IndexTo<Data, usize>
+    where
+    Data : ?Sized,
+{
+    /// Signed distance from `self` to `other` : `other - self`, or `None` if it doesn't fit in an `isize`.
+    #[inline]
+    pub const fn distance_to(self, other : Self) -> Option<isize>
+    {
+        if other.index >= self.index { 0isize.checked_add_unsigned(other.index - self.index) } else { 0isize.checked_sub_unsigned(self.index - other.index) }
+    }
+    /// Absolute distance between `self` and `other`.
+    #[inline]
+    pub const fn abs_diff(self, other : Self) -> usize { self.index.abs_diff(other.index) }
+
+    /// Offset this index by `delta`. Return `None` on overflow.
+    #[inline]
+    pub const fn checked_offset(self, delta : isize) -> Option<Self> { match self.index.checked_add_signed(delta) { Some(index) => Some(Self::from_index(index)), None => None } }
+    /// Offset this index by `delta`, clamping at `0` and `usize::MAX`.
+    #[inline]
+    pub const fn saturating_offset(self, delta : isize) -> Self { Self::from_index(self.index.saturating_add_signed(delta)) }
+    /// Offset this index by `delta`, wrapping around at the boundary of `usize`.
+    #[inline]
+    pub const fn wrapping_offset(self, delta : isize) -> Self { Self::from_index(self.index.wrapping_add_signed(delta)) }
+
+    /// Clamp this index to be valid for a collection of length `len`. Return `None` if `len == 0`.
+    #[inline]
+    pub const fn clamp_to(self, len : usize) -> Option<Self> { if len == 0 { None } else if self.index < len { Some(self) } else { Some(Self::from_index(len - 1)) } }
+
+    /// Retarget this index to a parallel collection, keeping the same position.
+    ///
+    /// Useful for struct-of-arrays layout, where the same position refer to the same row in every collection.
+    #[inline]
+    pub const fn reindex<U : ?Sized>(self) -> IndexTo<U> { IndexTo::from_index(self.index) }
 }
 
 impl<Data : ?Sized, Idx> Hash       for IndexTo<Data, Idx> where Idx : Hash       { #[inline] fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.index.hash(state); } }
 impl<Data : ?Sized, Idx> Clone      for IndexTo<Data, Idx> where Idx : Clone      { #[inline] fn clone(&self) -> Self { Self::from_index(self.index.clone()) } }
 impl<Data : ?Sized, Idx> Copy       for IndexTo<Data, Idx> where Idx : Copy       {}
-impl<Data : ?Sized, Idx> Debug      for IndexTo<Data, Idx> where Idx : Debug      { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { write!(f, "{}#{:?}", std::any::type_name::<Data>(), self.index) } }
+impl<Data : ?Sized, Idx> Debug      for IndexTo<Data, Idx> where Idx : Debug      { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { fmt_type_name(f, std::any::type_name::<Data>())?; write!(f, "#{:?}", self.index) } }
 impl<Data : ?Sized, Idx> Eq         for IndexTo<Data, Idx> where Idx : Eq         {}
+/// Only the inner index is compared (`Data` is the same on both side). Use [IndexTo::same_index] to compare index with a different `Data`.
 impl<Data : ?Sized, Idx> PartialEq  for IndexTo<Data, Idx> where Idx : PartialEq  { #[inline] fn eq(&self, other: &Self) -> bool { self.index == other.index } }
 impl<Data : ?Sized, Idx> Ord        for IndexTo<Data, Idx> where Idx : Ord        { #[inline] fn cmp(&self, other: &Self) -> std::cmp::Ordering { self.index.cmp(&other.index) } }
 impl<Data : ?Sized, Idx> PartialOrd for IndexTo<Data, Idx> where Idx : PartialOrd { #[inline] fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { self.index.partial_cmp(&other.index) } }
+impl<Data : ?Sized, Idx> AsRef<Idx> for IndexTo<Data, Idx> { #[inline] fn as_ref(&self) -> &Idx { &self.index } }
+/// `Hash`, `Eq` and `Ord` only use the inner index, so they are consistent with `Idx` as required by `Borrow`.
+///
+/// Allow to query a map keyed by typed index with a raw index. To query a map keyed by raw index with a typed index, use [IndexTo::as_index] : `map.get(idx.as_index())`.
+impl<Data : ?Sized, Idx> std::borrow::Borrow<Idx> for IndexTo<Data, Idx> { #[inline] fn borrow(&self) -> &Idx { &self.index } }
+
+/// Write a type name for `Debug`.
+///
+/// The module path of each type is removed when the "short-type-names" feature is enabled : `alloc::vec::Vec<my_crate::Node>` become `Vec<Node>`.
+pub(crate) fn fmt_type_name(f : &mut Formatter<'_>, name : &str) -> DResult
+{
+    #[cfg(feature = "short-type-names")]
+    {
+        use std::fmt::Write;
+        fn short_path(path : &str) -> &str { path.rsplit("::").next().unwrap_or(path) }
+
+        let mut start = 0;
+        for (i, c) in name.char_indices()
+        {
+            if !(c.is_alphanumeric() || c == '_' || c == ':')
+            {
+                f.write_str(short_path(&name[start..i]))?;
+                f.write_char(c)?;
+                start = i + c.len_utf8();
+            }
+        }
+        f.write_str(short_path(&name[start..]))
+    }
+    #[cfg(not(feature = "short-type-names"))]
+    f.write_str(name)
+}
 
 pub trait HaveTypedIndex<Idx=usize> : Index<Idx>
 {
     type IndexTo;
     type Output : ?Sized;
     /// return a strongly typed index for vector, slice, and str type.
+    ///
+    /// The `Data` of the returned index is the output of the indexing :
+    /// `vec.index_to(2)` return an `IndexTo<T>` (element form), while `vec.index_to(1..3)` return an `IndexTo<[T], Range<usize>>` (slice form).
+    ///
+    /// `From`/`Into` convert between `IndexTo<[T], Idx>` and `IndexTo<T, Idx>` : only the `Data` marker change, `Idx` is kept.
+    /// So a range index like `vec.index_to(1..3)` can't become an `IndexTo<T>`.
+    ///
+    /// ```
+    /// use typed_index::*;
+    ///
+    /// let v = vec![10, 20, 30];
+    /// let element : IndexTo<i32> = v.index_to(1);
+    /// let slice : IndexTo<[i32]> = element.into();
+    /// let back : IndexTo<i32> = slice.into();
+    /// assert_eq!(v[back], 20);
+    /// ```
     fn index_to(&self, index : Idx) -> Self::IndexTo;
 }
 impl<Idx, T> HaveTypedIndex<Idx> for T where T : Index<Idx> + ?Sized
@@ -169,6 +400,7 @@ mod tests {
     //use super::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_int_and_bool() 
     {
         use crate::*;
@@ -237,4 +469,273 @@ mod tests {
         *int_idx_2.get_mut(&mut int_and_bool) = 50;
         assert_eq!(int_idx_2.get(&int_and_bool), &50);
     }
+
+    #[test]
+    fn as_index()
+    {
+        use crate::*;
+
+        struct Foo;
+        let key = IndexTo::<Foo, String>::from_index("foo".to_owned());
+
+        assert_eq!(key.as_index(), "foo");
+        assert_eq!(AsRef::<String>::as_ref(&key), "foo");
+    }
+
+    #[test]
+    fn into_index()
+    {
+        use crate::*;
+
+        struct Foo;
+        let key = IndexTo::<Foo, String>::from_index("foo".to_owned());
+
+        let owned : String = key.into_index();
+        assert_eq!(owned, "foo");
+    }
+
+    #[test]
+    fn reindex()
+    {
+        use crate::*;
+
+        struct Name(&'static str);
+        struct Age(u32);
+
+        let names = vec![Name("Alice"), Name("Bob")];
+        let ages = [Age(30), Age(25)];
+
+        let bob : IndexTo<Name> = names.index_to(1);
+        let bob_age : IndexTo<Age> = bob.reindex();
+
+        assert_eq!(names[bob].0, "Bob");
+        assert_eq!(ages[bob_age].0, 25);
+        assert_eq!(bob.index(), bob_age.index());
+    }
+
+    #[test]
+    fn transparent_layout()
+    {
+        use crate::*;
+
+        struct Node;
+        assert_eq!(std::mem::size_of::<IndexTo<Node, u32>>(), std::mem::size_of::<u32>());
+        assert_eq!(std::mem::align_of::<IndexTo<Node, u32>>(), std::mem::align_of::<u32>());
+
+        let raw : &[u32] = &[3, 1, 4];
+        let typed = IndexTo::<Node, u32>::from_slice(raw);
+        assert_eq!(typed.len(), 3);
+        assert_eq!(typed[2].index(), 4);
+
+        assert_eq!(IndexTo::as_slice_of_indices(typed), raw);
+        assert_eq!(IndexTo::<Node, u32>::from_ref(&raw[1]).index(), 1);
+    }
+
+    #[test]
+    fn offset()
+    {
+        use crate::*;
+
+        let idx = IndexTo::<i32>::from_index(2);
+        let max = IndexTo::<i32>::from_index(usize::MAX);
+
+        assert_eq!(idx.checked_offset(3).map(IndexTo::index), Some(5));
+        assert_eq!(idx.checked_offset(-3), None);
+        assert_eq!(max.checked_offset(1), None);
+
+        assert_eq!(idx.saturating_offset(-1).index(), 1);
+        assert_eq!(idx.saturating_offset(-5).index(), 0);
+        assert_eq!(max.saturating_offset(1).index(), usize::MAX);
+
+        assert_eq!(idx.wrapping_offset(-3).index(), usize::MAX);
+        assert_eq!(max.wrapping_offset(3).index(), 2);
+    }
+
+    #[test]
+    fn erased()
+    {
+        use crate::*;
+        use std::collections::HashMap;
+
+        struct A;
+        struct B;
+
+        let mut map = HashMap::new();
+        map.insert(IndexTo::<A>::from_index(0).erased(), "a");
+        map.insert(IndexTo::<B>::from_index(0).erased(), "b");
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&IndexTo::<A>::from_index(0).erased()], "a");
+        assert_eq!(map[&IndexTo::<B>::from_index(0).erased()], "b");
+    }
+
+    #[test]
+    fn eq_pos()
+    {
+        use crate::*;
+
+        struct Node;
+
+        let small = IndexTo::<Node, u32>::from_index(3);
+        let big = IndexTo::<Node, usize>::from_index(3);
+
+        assert!(small.eq_pos(&big));
+        assert!(big.eq_pos(&small));
+        assert!(!small.eq_pos(&IndexTo::<Node, usize>::from_index(4)));
+
+        // Negative positions
+        let neg = IndexTo::<Node, i32>::from_index(-1);
+        assert!(neg.eq_pos(&neg));
+        assert!(neg.eq_pos(&IndexTo::<Node, i64>::from_index(-1)));
+        assert!(!neg.eq_pos(&IndexTo::<Node, i64>::from_index(1)));
+        assert!(!neg.eq_pos(&IndexTo::<Node, u128>::from_index(u128::MAX)));
+
+        // Positions that don't fit in an `i128`
+        let huge = IndexTo::<Node, u128>::from_index(u128::MAX);
+        assert!(huge.eq_pos(&huge));
+        assert!(!huge.eq_pos(&big));
+    }
+
+    #[test]
+    fn send_sync_only_depend_on_idx()
+    {
+        use crate::*;
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        fn assert_send_sync<T : Send + Sync>() {}
+
+        assert_send_sync::<IndexTo<Cell<u8>, u32>>();
+        assert_send_sync::<IndexTo<Rc<u8>>>();
+        assert_send_sync::<IndexTo<str>>();
+    }
+
+    #[test]
+    fn as_usize()
+    {
+        use crate::*;
+
+        assert_eq!(IndexTo::<i32, u16>::from_index(513).as_usize(), 513);
+        assert_eq!(IndexTo::<i32, u64>::from_index(7).try_as_usize(), Some(7));
+        assert_eq!(IndexTo::<i32, u64>::from_index(usize::MAX as u64).try_as_usize(), Some(usize::MAX));
+        assert_eq!(IndexTo::<i32, u128>::from_index(usize::MAX as u128 + 1).try_as_usize(), None);
+        assert_eq!(IndexTo::<i32, i64>::from_index(-1).try_as_usize(), None);
+    }
+
+    #[test]
+    fn same_index()
+    {
+        use crate::*;
+
+        struct A;
+        struct B;
+
+        let a = IndexTo::<A, u32>::from_index(4);
+        assert!(a.same_index(&IndexTo::<B, u32>::from_index(4)));
+        assert!(!a.same_index(&IndexTo::<B, u32>::from_index(5)));
+        assert!(a.same_index(&a));
+    }
+
+    #[test]
+    fn borrow_as_map_key()
+    {
+        use crate::*;
+        use std::borrow::Borrow;
+        use std::collections::{BTreeSet, HashMap, HashSet};
+
+        struct Data;
+
+        let mut map : HashMap<u32, &str> = HashMap::new();
+        map.insert(1, "one");
+
+        assert_eq!(map.get(IndexTo::<Data, u32>::from_index(1).as_index()), Some(&"one"));
+        assert_eq!(map.get(IndexTo::<Data, u32>::from_index(2).as_index()), None);
+        assert_eq!(map.get(&1), Some(&"one"));
+
+        let mut typed_map : HashMap<IndexTo<Data, u32>, &str> = HashMap::new();
+        typed_map.insert(IndexTo::from_index(1), "one");
+        assert_eq!(typed_map.get(&1), Some(&"one"));
+
+        let set : BTreeSet<IndexTo<Data, u32>> = [IndexTo::from_index(3)].into_iter().collect();
+        assert!(set.contains(&3));
+
+        // Borrowing a raw integer must stay unambiguous
+        let raw : HashSet<u32> = [3].into_iter().collect();
+        let x : u32 = 3;
+        assert!(raw.contains(x.borrow()));
+    }
+
+    #[test]
+    #[cfg(not(feature = "short-type-names"))]
+    fn debug_full_type_name()
+    {
+        use crate::*;
+
+        struct Node;
+        assert_eq!(format!("{:?}", IndexTo::<Node>::from_index(3)), "typed_index::tests::debug_full_type_name::Node#3");
+        assert_eq!(format!("{:?}", IndexTo::<str, u8>::from_index(3)), "str#3");
+    }
+
+    #[test]
+    #[cfg(feature = "short-type-names")]
+    fn debug_short_type_name()
+    {
+        use crate::*;
+
+        struct Node;
+        assert_eq!(format!("{:?}", IndexTo::<Node>::from_index(3)), "Node#3");
+        assert_eq!(format!("{:?}", IndexTo::<Vec<Node>>::from_index(3)), "Vec<Node>#3");
+        assert_eq!(format!("{:?}", IndexTo::<(Node, [Node])>::from_index(3)), "(Node, [Node])#3");
+    }
+
+    #[test]
+    fn raw_round_trip()
+    {
+        use crate::*;
+
+        struct Node;
+        let idx = IndexTo::<Node, u32>::from_index(12);
+
+        let raw : u32 = idx.into_raw();
+        assert_eq!(raw, 12);
+
+        // SAFETY: `raw` come from an index of a `Node` collection
+        let back = unsafe { IndexTo::<Node, u32>::from_raw(raw) };
+        assert_eq!(back, idx);
+    }
+
+    #[test]
+    fn clamp_to()
+    {
+        use crate::*;
+
+        let idx = IndexTo::<i32>::from_index(4);
+        assert_eq!(idx.clamp_to(0), None);
+        assert_eq!(idx.clamp_to(10), Some(idx));
+        assert_eq!(idx.clamp_to(5), Some(idx));
+        assert_eq!(idx.clamp_to(3).map(IndexTo::index), Some(2));
+    }
+
+    #[test]
+    fn distance()
+    {
+        use crate::*;
+
+        let a = IndexTo::<i32>::from_index(2);
+        let b = IndexTo::<i32>::from_index(7);
+
+        assert_eq!(a.distance_to(b), Some(5));
+        assert_eq!(b.distance_to(a), Some(-5));
+        assert_eq!(a.distance_to(a), Some(0));
+
+        let zero = IndexTo::<i32>::from_index(0);
+        assert_eq!(zero.distance_to(IndexTo::from_index(isize::MAX as usize)), Some(isize::MAX));
+        assert_eq!(zero.distance_to(IndexTo::from_index(isize::MAX as usize + 1)), None);
+        assert_eq!(IndexTo::<i32>::from_index(isize::MIN.unsigned_abs()).distance_to(zero), Some(isize::MIN));
+        assert_eq!(IndexTo::<i32>::from_index(usize::MAX).distance_to(zero), None);
+
+        assert_eq!(a.abs_diff(b), 5);
+        assert_eq!(b.abs_diff(a), 5);
+        assert_eq!(a.abs_diff(a), 0);
+    }
 }
\ No newline at end of file
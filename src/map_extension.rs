@@ -0,0 +1,54 @@
+use crate::*;
+use std::collections::{BTreeMap, HashMap};
+
+/// Extension methods for maps, to see their keys as strongly typed index : `IndexTo<V, K>`.
+pub trait TypedKeys<K, V>
+{
+    /// Iterate over the keys of the map as typed index.
+    fn key_indices(&self) -> impl Iterator<Item = IndexTo<V, K>> where K : Copy;
+}
+
+/// The iteration order is unspecified, like [HashMap::keys].
+impl<K, V, S> TypedKeys<K, V> for HashMap<K, V, S>
+{
+    #[inline]
+    fn key_indices(&self) -> impl Iterator<Item = IndexTo<V, K>> where K : Copy { self.keys().map(|k| IndexTo::from_index(*k)) }
+}
+
+
+/// Unlike `HashMap`, the iteration order is deterministic : the keys are yielded in sorted order, like [BTreeMap::keys].
+impl<K, V> TypedKeys<K, V> for BTreeMap<K, V>
+{
+    #[inline]
+    fn key_indices(&self) -> impl Iterator<Item = IndexTo<V, K>> where K : Copy { self.keys().map(|k| IndexTo::from_index(*k)) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn hash_map_key_indices()
+    {
+        let map : HashMap<u32, &str> = [(1, "one"), (2, "two"), (3, "three")].into_iter().collect();
+
+        let mut indices : Vec<IndexTo<&str, u32>> = map.key_indices().collect();
+        indices.sort();
+
+        assert_eq!(indices, [IndexTo::from_index(1), IndexTo::from_index(2), IndexTo::from_index(3)]);
+        assert_eq!(indices.iter().map(|i| map[*i]).collect::<Vec<_>>(), ["one", "two", "three"]);
+    }
+
+    #[test]
+    fn btree_map_key_indices()
+    {
+        let map : BTreeMap<u32, &str> = [(3, "three"), (1, "one"), (2, "two")].into_iter().collect();
+
+        let indices : Vec<IndexTo<&str, u32>> = map.key_indices().collect();
+
+        assert_eq!(indices, [IndexTo::from_index(1), IndexTo::from_index(2), IndexTo::from_index(3)]);
+        assert_eq!(indices.iter().map(|i| map[*i]).collect::<Vec<_>>(), ["one", "two", "three"]);
+    }
+}
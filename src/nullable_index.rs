@@ -0,0 +1,81 @@
+use crate::*;
+
+/// A strongly typed index that use `usize::MAX` as a null sentinel.
+///
+/// Have the same size as `usize`, with an `Option`-like api. Convert it to an `Option<IndexTo<Data>>` with [NullableIndex::get].
+pub struct NullableIndex<Data>
+    where
+    Data : ?Sized,
+{
+    index : IndexTo<Data>,
+}
+
+impl<Data> NullableIndex<Data>
+    where
+    Data : ?Sized,
+{
+    /// The null sentinel value.
+    pub const NULL : usize = usize::MAX;
+
+    #[inline]
+    pub const fn null() -> Self { Self { index : IndexTo::from_index(Self::NULL) } }
+    /// Wrap `index`. The result is null if `index` is the null sentinel.
+    #[inline]
+    pub const fn new(index : IndexTo<Data>) -> Self { Self { index } }
+
+    #[inline]
+    pub const fn is_null(self) -> bool { self.index.index == Self::NULL }
+
+    #[inline]
+    pub const fn get(self) -> Option<IndexTo<Data>> { if self.is_null() { None } else { Some(self.index) } }
+}
+
+impl<Data : ?Sized> Default for NullableIndex<Data> { #[inline] fn default() -> Self { Self::null() } }
+
+impl<Data : ?Sized> From<IndexTo<Data>> for NullableIndex<Data> { #[inline] fn from(index : IndexTo<Data>) -> Self { Self::new(index) } }
+impl<Data : ?Sized> From<Option<IndexTo<Data>>> for NullableIndex<Data> { #[inline] fn from(index : Option<IndexTo<Data>>) -> Self { index.map_or(Self::null(), Self::new) } }
+impl<Data : ?Sized> From<NullableIndex<Data>> for Option<IndexTo<Data>> { #[inline] fn from(index : NullableIndex<Data>) -> Self { index.get() } }
+
+impl<Data : ?Sized> Hash       for NullableIndex<Data> { #[inline] fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.index.hash(state); } }
+impl<Data : ?Sized> Clone      for NullableIndex<Data> { #[inline] fn clone(&self) -> Self { *self } }
+impl<Data : ?Sized> Copy       for NullableIndex<Data> {}
+impl<Data : ?Sized> Debug      for NullableIndex<Data> { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { match self.get() { Some(index) => index.fmt(f), None => { fmt_type_name(f, std::any::type_name::<Data>())?; write!(f, "#null") } } } }
+impl<Data : ?Sized> Eq         for NullableIndex<Data> {}
+impl<Data : ?Sized> PartialEq  for NullableIndex<Data> { #[inline] fn eq(&self, other: &Self) -> bool { self.index == other.index } }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn null()
+    {
+        let idx = NullableIndex::<i32>::null();
+        assert!(idx.is_null());
+        assert_eq!(idx.get(), None);
+        assert_eq!(idx, NullableIndex::default());
+        assert_eq!(idx, NullableIndex::new(IndexTo::from_index(usize::MAX)));
+        assert_eq!(std::mem::size_of::<NullableIndex<i32>>(), std::mem::size_of::<usize>());
+    }
+
+    #[test]
+    fn valid_index()
+    {
+        let v = vec![1, 2, 3];
+        let idx = NullableIndex::new(v.index_to(2));
+        assert!(!idx.is_null());
+        assert_eq!(idx.get(), Some(v.index_to(2)));
+        assert_eq!(v[idx.get().unwrap()], 3);
+    }
+
+    #[test]
+    fn to_option()
+    {
+        let some : Option<IndexTo<i32>> = NullableIndex::from(Some(IndexTo::from_index(4))).into();
+        assert_eq!(some, Some(IndexTo::from_index(4)));
+
+        let none : Option<IndexTo<i32>> = NullableIndex::from(None).into();
+        assert_eq!(none, None);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::*;
+
+/// A typed index with the `Data` type erased, that keep the `Data` type name to stay totally ordered.
+///
+/// Index are compared by position first, then by `Data` type name,
+/// so index of different `Data` at the same position are different and can live in the same `BTreeSet`.
+///
+/// Based on [std::any::type_name], so two different type with the same name are considered equal.
+pub struct OrderedIndex<Idx=usize>
+{
+    index : Idx,
+    type_name : &'static str,
+}
+
+impl<Idx> OrderedIndex<Idx>
+{
+    #[inline]
+    pub fn new<Data : ?Sized>(index : IndexTo<Data, Idx>) -> Self { Self { index : index.index, type_name : std::any::type_name::<Data>() } }
+
+    #[inline]
+    pub const fn index(self) -> Idx where Idx : Copy { self.index }
+    /// The type name of the erased `Data`.
+    #[inline]
+    pub const fn type_name(&self) -> &'static str { self.type_name }
+}
+
+impl<Data, Idx> IndexTo<Data, Idx>
+    where
+    Data : ?Sized,
+{
+    /// Erase the `Data` type, but keep a total order between index of different `Data`. See [OrderedIndex].
+    #[inline]
+    pub fn ordered(self) -> OrderedIndex<Idx> { OrderedIndex::new(self) }
+}
+
+impl<Data : ?Sized, Idx> From<IndexTo<Data, Idx>> for OrderedIndex<Idx> { #[inline] fn from(index : IndexTo<Data, Idx>) -> Self { Self::new(index) } }
+
+impl<Idx> Hash       for OrderedIndex<Idx> where Idx : Hash       { #[inline] fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.index.hash(state); self.type_name.hash(state); } }
+impl<Idx> Clone      for OrderedIndex<Idx> where Idx : Clone      { #[inline] fn clone(&self) -> Self { Self { index : self.index.clone(), type_name : self.type_name } } }
+impl<Idx> Copy       for OrderedIndex<Idx> where Idx : Copy       {}
+impl<Idx> Debug      for OrderedIndex<Idx> where Idx : Debug      { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { fmt_type_name(f, self.type_name)?; write!(f, "#{:?}", self.index) } }
+impl<Idx> Eq         for OrderedIndex<Idx> where Idx : Eq         {}
+impl<Idx> PartialEq  for OrderedIndex<Idx> where Idx : PartialEq  { #[inline] fn eq(&self, other: &Self) -> bool { self.index == other.index && self.type_name == other.type_name } }
+impl<Idx> Ord        for OrderedIndex<Idx> where Idx : Ord        { #[inline] fn cmp(&self, other: &Self) -> std::cmp::Ordering { self.index.cmp(&other.index).then_with(|| self.type_name.cmp(other.type_name)) } }
+impl<Idx> PartialOrd for OrderedIndex<Idx> where Idx : PartialOrd { #[inline] fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { match self.index.partial_cmp(&other.index) { Some(std::cmp::Ordering::Equal) => Some(self.type_name.cmp(other.type_name)), ord => ord } } }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::collections::BTreeSet;
+
+    struct A;
+    struct B;
+
+    #[test]
+    fn same_position_different_data()
+    {
+        let a = IndexTo::<A>::from_index(0).ordered();
+        let b = IndexTo::<B>::from_index(0).ordered();
+        assert_ne!(a, b);
+
+        let mut set = BTreeSet::new();
+        set.insert(a);
+        set.insert(b);
+        set.insert(IndexTo::<A>::from_index(0).into());
+
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&a));
+        assert!(set.contains(&b));
+    }
+
+    #[test]
+    fn ordered_by_position_first()
+    {
+        let set : BTreeSet<_> = [IndexTo::<B>::from_index(0).ordered(), IndexTo::<A>::from_index(1).ordered(), IndexTo::<A>::from_index(0).ordered()].into_iter().collect();
+        let order : Vec<_> = set.iter().map(|i| i.index()).collect();
+        assert_eq!(order, [0, 0, 1]);
+    }
+}
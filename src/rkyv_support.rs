@@ -0,0 +1,103 @@
+use crate::*;
+
+use rkyv::{Archive, Archived, Deserialize, Place, Portable, Serialize};
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::Fallible;
+
+/// The archived form of an [IndexTo], only made of the archived inner index.
+#[repr(transparent)]
+pub struct ArchivedIndexTo<Data, Idx>
+    where
+    Data : ?Sized,
+    Idx : Archive,
+{
+    index : Archived<Idx>,
+    index_data : PhantomData<fn() -> Data>,
+}
+
+impl<Data, Idx> ArchivedIndexTo<Data, Idx>
+    where
+    Data : ?Sized,
+    Idx : Archive,
+{
+    /// The archived inner index.
+    #[inline]
+    pub const fn index(&self) -> &Archived<Idx> { &self.index }
+}
+
+// SAFETY: `ArchivedIndexTo` is `repr(transparent)` over `Archived<Idx>`, which is `Portable`.
+unsafe impl<Data : ?Sized, Idx : Archive> Portable for ArchivedIndexTo<Data, Idx> where Archived<Idx> : Portable {}
+
+// SAFETY: `ArchivedIndexTo` is `repr(transparent)` over `Archived<Idx>`, so checking the inner index is enough.
+unsafe impl<Data, Idx, C> CheckBytes<C> for ArchivedIndexTo<Data, Idx>
+    where
+    Data : ?Sized,
+    Idx : Archive,
+    Archived<Idx> : CheckBytes<C>,
+    C : Fallible + ?Sized,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        Archived::<Idx>::check_bytes(value.cast(), context)
+    }
+}
+
+impl<Data, Idx> Archive for IndexTo<Data, Idx>
+    where
+    Data : ?Sized,
+    Idx : Archive,
+{
+    type Archived = ArchivedIndexTo<Data, Idx>;
+    type Resolver = Idx::Resolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: `ArchivedIndexTo` is `repr(transparent)` over `Archived<Idx>`.
+        let out = unsafe { out.cast_unchecked::<Archived<Idx>>() };
+        self.index.resolve(resolver, out);
+    }
+}
+
+impl<Data, Idx, S> Serialize<S> for IndexTo<Data, Idx>
+    where
+    Data : ?Sized,
+    Idx : Serialize<S>,
+    S : Fallible + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.index.serialize(serializer)
+    }
+}
+
+impl<Data, Idx, D> Deserialize<IndexTo<Data, Idx>, D> for ArchivedIndexTo<Data, Idx>
+    where
+    Data : ?Sized,
+    Idx : Archive,
+    Archived<Idx> : Deserialize<Idx, D>,
+    D : Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<IndexTo<Data, Idx>, D::Error> {
+        let index = self.index.deserialize(deserializer)?;
+        Ok(IndexTo::from_index(index))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use rkyv::rancor::Error;
+
+    #[test]
+    fn rkyv_round_trip()
+    {
+        let idx = IndexTo::<u64, u32>::from_index(42);
+
+        let bytes = rkyv::to_bytes::<Error>(&idx).unwrap();
+        assert_eq!(bytes.len(), std::mem::size_of::<u32>());
+
+        let archived = rkyv::access::<ArchivedIndexTo<u64, u32>, Error>(&bytes).unwrap();
+        assert_eq!(archived.index().to_native(), 42);
+
+        let deserialized : IndexTo<u64, u32> = rkyv::deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(deserialized, idx);
+    }
+}
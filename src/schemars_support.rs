@@ -0,0 +1,41 @@
+use crate::*;
+
+use std::borrow::Cow;
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+// `IndexTo` is serialized as its inner index, so it share the exact same schema.
+impl<Data, Idx> JsonSchema for IndexTo<Data, Idx>
+where
+    Data: ?Sized,
+    Idx: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        Idx::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Idx::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Idx::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        Idx::json_schema(generator)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use schemars::{schema_for, JsonSchema};
+
+    #[test]
+    fn schema_is_the_inner_index_schema()
+    {
+        assert_eq!(schema_for!(IndexTo<String, u32>), schema_for!(u32));
+        assert_eq!(IndexTo::<String, u32>::schema_name(), u32::schema_name());
+    }
+}
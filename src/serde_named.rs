@@ -0,0 +1,78 @@
+//! Serialize an [IndexTo] as a struct with a single `idx` field, like `{ "idx": 3 }`, instead of a bare scalar.
+//!
+//! Opt-in per field with `#[serde(with = "typed_index::serde_named")]` :
+//!
+//! ```
+//! use typed_index::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config
+//! {
+//!     #[serde(with = "typed_index::serde_named")]
+//!     selected : IndexTo<String>,
+//! }
+//! ```
+use crate::*;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize)]
+#[serde(rename = "IndexTo")]
+struct NamedRef<'a, Idx>
+{
+    idx : &'a Idx,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "IndexTo")]
+struct Named<Idx>
+{
+    idx : Idx,
+}
+
+pub fn serialize<Data, Idx, S>(index : &IndexTo<Data, Idx>, serializer : S) -> Result<S::Ok, S::Error>
+    where
+    Data : ?Sized,
+    Idx : Serialize,
+    S : Serializer,
+{
+    NamedRef { idx : &index.index }.serialize(serializer)
+}
+
+pub fn deserialize<'de, Data, Idx, D>(deserializer : D) -> Result<IndexTo<Data, Idx>, D::Error>
+    where
+    Data : ?Sized,
+    Idx : Deserialize<'de>,
+    D : Deserializer<'de>,
+{
+    let Named { idx } = Named::deserialize(deserializer)?;
+    Ok(IndexTo::from_index(idx))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Config
+    {
+        #[serde(with = "crate::serde_named")]
+        selected : IndexTo<String>,
+        default : IndexTo<String>,
+    }
+
+    #[test]
+    fn named_round_trip()
+    {
+        let config = Config { selected : IndexTo::from_index(3), default : IndexTo::from_index(1) };
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"selected":{"idx":3},"default":1}"#);
+
+        let deserialized : Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, config);
+    }
+}
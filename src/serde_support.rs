@@ -32,3 +32,25 @@ where
         Ok(IndexTo::from_index(index))
     }
 }
+
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod tests {
+    use crate::*;
+
+    struct Frame;
+
+    #[test]
+    fn bincode_is_transparent()
+    {
+        let idx = IndexTo::<Frame, u16>::from_index(513);
+
+        let bytes = bincode::serialize(&idx).unwrap();
+        assert_eq!(bytes.len(), std::mem::size_of::<u16>());
+        assert_eq!(bytes, bincode::serialize(&513u16).unwrap());
+
+        let deserialized : IndexTo<Frame, u16> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized, idx);
+    }
+}
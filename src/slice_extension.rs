@@ -0,0 +1,329 @@
+use crate::*;
+use std::cmp::Ordering;
+
+/// Extension methods for slices (and `Vec` through deref) that work with strongly typed index.
+pub trait TypedSlice<T>
+{
+    /// Return a strongly typed index only if `index` is in bounds for this slice.
+    ///
+    /// Checked version of [HaveTypedIndex::index_to].
+    fn index_to_checked(&self, index : usize) -> Option<IndexTo<T>>;
+    /// The typed index `back` elements from the end : `from_end(0)` is the last element. Return `None` if `back >= len`.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_end(&self, back : usize) -> Option<IndexTo<T>>;
+
+    /// Divides one slice into two at a typed index, like [slice::split_at].
+    ///
+    /// The first will contain all indices from `[0, mid)` and the second will contain all indices from `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    fn split_at_typed(&self, mid : IndexTo<T>) -> (&[T], &[T]);
+
+    /// Divides one mutable slice into two at a typed index, like [slice::split_at_mut].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    fn split_at_typed_mut(&mut self, mid : IndexTo<T>) -> (&mut [T], &mut [T]);
+
+    /// Index with a smaller inner index (`u8`, `u16`, `u32`...), converted to `usize`.
+    ///
+    /// `[T]` and `Vec<T>` are only indexable by `IndexTo<T, usize>`, so `v[IndexTo::from_index(1)]` can infer the inner index type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or doesn't fit in a `usize`.
+    fn index_small<Idx>(&self, index : IndexTo<T, Idx>) -> &T where Idx : TryInto<usize>;
+    /// Mutable version of [TypedSlice::index_small].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or doesn't fit in a `usize`.
+    fn index_small_mut<Idx>(&mut self, index : IndexTo<T, Idx>) -> &mut T where Idx : TryInto<usize>;
+
+    /// Like [slice::windows], but also yield the typed index of the first element of each window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    fn windows_typed<'a>(&'a self, size : usize) -> impl Iterator<Item = (IndexTo<T>, &'a [T])> where T : 'a;
+
+    /// Like [slice::chunks], but also yield the typed index of the first element of each chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    fn chunks_typed<'a>(&'a self, size : usize) -> impl Iterator<Item = (IndexTo<T>, &'a [T])> where T : 'a;
+
+    /// The typed index (in order) of every element that match the predicate.
+    fn indices_where<F>(&self, pred : F) -> Vec<IndexTo<T>> where F : FnMut(&T) -> bool;
+
+    /// The typed index of the maximum element, or `None` if the slice is empty.
+    ///
+    /// If several elements are equally maximum, the index of the first one is returned.
+    fn max_index(&self) -> Option<IndexTo<T>> where T : Ord { self.max_index_by(T::cmp) }
+    /// The typed index of the minimum element, or `None` if the slice is empty.
+    ///
+    /// If several elements are equally minimum, the index of the first one is returned.
+    fn min_index(&self) -> Option<IndexTo<T>> where T : Ord { self.min_index_by(T::cmp) }
+
+    /// Like [TypedSlice::max_index], with the maximum computed from the key returned by `f`.
+    fn max_index_by_key<K, F>(&self, mut f : F) -> Option<IndexTo<T>> where K : Ord, F : FnMut(&T) -> K { self.max_index_by(|a, b| f(a).cmp(&f(b))) }
+    /// Like [TypedSlice::min_index], with the minimum computed from the key returned by `f`.
+    fn min_index_by_key<K, F>(&self, mut f : F) -> Option<IndexTo<T>> where K : Ord, F : FnMut(&T) -> K { self.min_index_by(|a, b| f(a).cmp(&f(b))) }
+
+    /// Like [TypedSlice::max_index], with the maximum computed from the `compare` function.
+    fn max_index_by<F>(&self, compare : F) -> Option<IndexTo<T>> where F : FnMut(&T, &T) -> Ordering;
+    /// Like [TypedSlice::min_index], with the minimum computed from the `compare` function.
+    fn min_index_by<F>(&self, mut compare : F) -> Option<IndexTo<T>> where F : FnMut(&T, &T) -> Ordering { self.max_index_by(|a, b| compare(b, a)) }
+
+    /// One typed index per element, in order : `[index_to(0), index_to(1), ...]`.
+    fn identity_indices(&self) -> Vec<IndexTo<T>>;
+
+    /// Collect a reference to the element of each index, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds.
+    fn gather<I>(&self, indices : I) -> Vec<&T> where I : IntoIterator<Item = IndexTo<T>>;
+    /// Collect a reference to the element of each index, in order. Return `None` if an index is out of bounds.
+    fn try_gather<I>(&self, indices : I) -> Option<Vec<&T>> where I : IntoIterator<Item = IndexTo<T>>;
+}
+
+impl<T> TypedSlice<T> for [T]
+{
+    #[inline]
+    fn index_to_checked(&self, index : usize) -> Option<IndexTo<T>> { if index < self.len() { Some(IndexTo::from_index(index)) } else { None } }
+    #[inline]
+    fn from_end(&self, back : usize) -> Option<IndexTo<T>> { if back < self.len() { Some(IndexTo::from_index(self.len() - 1 - back)) } else { None } }
+    #[inline]
+    fn split_at_typed(&self, mid : IndexTo<T>) -> (&[T], &[T]) { self.split_at(mid.index()) }
+    #[inline]
+    fn split_at_typed_mut(&mut self, mid : IndexTo<T>) -> (&mut [T], &mut [T]) { self.split_at_mut(mid.index()) }
+    #[inline]
+    #[track_caller]
+    fn index_small<Idx>(&self, index : IndexTo<T, Idx>) -> &T where Idx : TryInto<usize> { &self[index.try_as_usize().expect("index doesn't fit in a usize")] }
+    #[inline]
+    #[track_caller]
+    fn index_small_mut<Idx>(&mut self, index : IndexTo<T, Idx>) -> &mut T where Idx : TryInto<usize> { &mut self[index.try_as_usize().expect("index doesn't fit in a usize")] }
+    #[inline]
+    fn windows_typed<'a>(&'a self, size : usize) -> impl Iterator<Item = (IndexTo<T>, &'a [T])> where T : 'a { self.windows(size).enumerate().map(|(i, w)| (IndexTo::from_index(i), w)) }
+    #[inline]
+    fn chunks_typed<'a>(&'a self, size : usize) -> impl Iterator<Item = (IndexTo<T>, &'a [T])> where T : 'a { self.chunks(size).enumerate().map(move |(i, c)| (IndexTo::from_index(i * size), c)) }
+
+    fn indices_where<F>(&self, mut pred : F) -> Vec<IndexTo<T>> where F : FnMut(&T) -> bool
+    {
+        self.iter().enumerate().filter(|(_, v)| pred(v)).map(|(i, _)| IndexTo::from_index(i)).collect()
+    }
+
+    fn max_index_by<F>(&self, mut compare : F) -> Option<IndexTo<T>> where F : FnMut(&T, &T) -> Ordering
+    {
+        self.iter().enumerate()
+            .reduce(|best, cur| if compare(cur.1, best.1) == Ordering::Greater { cur } else { best })
+            .map(|(i, _)| IndexTo::from_index(i))
+    }
+
+    fn identity_indices(&self) -> Vec<IndexTo<T>> { (0..self.len()).map(IndexTo::from_index).collect() }
+
+    fn gather<I>(&self, indices : I) -> Vec<&T> where I : IntoIterator<Item = IndexTo<T>> { indices.into_iter().map(|i| &self[i]).collect() }
+    fn try_gather<I>(&self, indices : I) -> Option<Vec<&T>> where I : IntoIterator<Item = IndexTo<T>> { indices.into_iter().map(|i| self.get(i.index())).collect() }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn index_to_checked()
+    {
+        let v = [10, 20, 30];
+        assert_eq!(v.index_to_checked(1), Some(IndexTo::from_index(1)));
+        assert_eq!(v[v.index_to_checked(2).unwrap()], 30);
+        assert_eq!(v.index_to_checked(3), None);
+
+        let empty : Vec<i32> = Vec::new();
+        assert_eq!(empty.index_to_checked(0), None);
+    }
+
+    #[test]
+    fn split_at_typed()
+    {
+        let v = vec![1, 2, 3, 4];
+
+        assert_eq!(v.split_at_typed(v.index_to(0)), (&[][..], &[1, 2, 3, 4][..]));
+        assert_eq!(v.split_at_typed(v.index_to(2)), (&[1, 2][..], &[3, 4][..]));
+        assert_eq!(v.split_at_typed(v.index_to(4)), (&[1, 2, 3, 4][..], &[][..]));
+    }
+
+    #[test]
+    fn index_small()
+    {
+        let mut v = vec![10, 20, 30];
+
+        let idx = IndexTo::<i32, u32>::from_index(1);
+        assert_eq!(*v.index_small(idx), 20);
+        *v.index_small_mut(idx) = 21;
+        *v.as_mut_slice().index_small_mut(IndexTo::<i32, u16>::from_index(2)) = 31;
+        assert_eq!(v, [10, 21, 31]);
+        assert_eq!(*v.as_slice().index_small(IndexTo::<i32, u8>::from_index(0)), 10);
+
+        // Explicit conversion to a `usize` index
+        assert_eq!(v[IndexTo::<i32>::from(idx)], 21);
+
+        // The inner index of an untyped `from_index` is still inferred
+        assert_eq!(v[IndexTo::from_index(1)], 21);
+        assert_eq!(IndexTo::from_index(2).get(&v), &31);
+    }
+
+    #[test]
+    fn split_at_typed_mut()
+    {
+        let mut v = vec![1, 2, 3, 4];
+        let mid = v.index_to(1);
+
+        let (left, right) = v.split_at_typed_mut(mid);
+        left[0] = 10;
+        right[0] = 20;
+        assert_eq!(v, [10, 20, 3, 4]);
+
+        let len = v.index_to(v.len());
+        let (left, right) = v.split_at_typed_mut(len);
+        assert_eq!(left.len(), 4);
+        assert!(right.is_empty());
+    }
+
+    #[test]
+    fn windows_typed()
+    {
+        let v = [1, 2, 3, 4];
+        let windows : Vec<_> = v.windows_typed(3).collect();
+
+        assert_eq!(windows, [(v.index_to(0), &[1, 2, 3][..]), (v.index_to(1), &[2, 3, 4][..])]);
+        for (idx, window) in windows { assert_eq!(v[idx], window[0]); }
+
+        assert_eq!(v.windows_typed(5).count(), 0);
+    }
+
+    #[test]
+    fn chunks_typed()
+    {
+        let v = [1, 2, 3, 4, 5];
+        let chunks : Vec<_> = v.chunks_typed(2).collect();
+
+        assert_eq!(chunks, [(v.index_to(0), &[1, 2][..]), (v.index_to(2), &[3, 4][..]), (v.index_to(4), &[5][..])]);
+        for (idx, chunk) in chunks { assert_eq!(v[idx], chunk[0]); }
+    }
+
+    #[test]
+    #[should_panic]
+    fn windows_typed_zero()
+    {
+        let _ = [1, 2, 3].windows_typed(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunks_typed_zero()
+    {
+        let _ = [1, 2, 3].chunks_typed(0);
+    }
+
+    #[test]
+    fn indices_where()
+    {
+        let v = vec![1, 2, 3, 4, 5, 6];
+
+        assert_eq!(v.indices_where(|_| true), (0..v.len()).map(|i| v.index_to(i)).collect::<Vec<_>>());
+        assert!(v.indices_where(|x| *x > 10).is_empty());
+
+        let even = v.indices_where(|x| x % 2 == 0);
+        assert_eq!(even, [v.index_to(1), v.index_to(3), v.index_to(5)]);
+        assert!(even.iter().all(|i| v[*i] % 2 == 0));
+    }
+
+    #[test]
+    fn max_min_index()
+    {
+        let empty : [i32; 0] = [];
+        assert_eq!(empty.max_index(), None);
+        assert_eq!(empty.min_index(), None);
+
+        let single = [7];
+        assert_eq!(single.max_index(), Some(single.index_to(0)));
+        assert_eq!(single.min_index(), Some(single.index_to(0)));
+
+        let v = [3, 9, 1, 4];
+        assert_eq!(v.max_index(), Some(v.index_to(1)));
+        assert_eq!(v.min_index(), Some(v.index_to(2)));
+    }
+
+    #[test]
+    fn max_min_index_ties()
+    {
+        let v = [1, 5, 0, 5, 0];
+        assert_eq!(v.max_index(), Some(v.index_to(1)));
+        assert_eq!(v.min_index(), Some(v.index_to(2)));
+    }
+
+    #[test]
+    fn max_min_index_by()
+    {
+        let v = ["bb", "a", "ccc", "dd"];
+        assert_eq!(v.max_index_by_key(|s| s.len()), Some(v.index_to(2)));
+        assert_eq!(v.min_index_by_key(|s| s.len()), Some(v.index_to(1)));
+        assert_eq!(v.max_index_by_key(|s| s.len() % 3), Some(v.index_to(0)));
+
+        assert_eq!(v.max_index_by(|a, b| b.cmp(a)), Some(v.index_to(1)));
+        assert_eq!(v.min_index_by(|a, b| b.cmp(a)), Some(v.index_to(3)));
+    }
+
+    #[test]
+    fn gather()
+    {
+        let v = ['a', 'b', 'c', 'd'];
+        let indices = [v.index_to(3), v.index_to(0), v.index_to(3)];
+
+        assert_eq!(v.gather(indices), [&'d', &'a', &'d']);
+        assert_eq!(v.try_gather(indices), Some(vec![&'d', &'a', &'d']));
+        assert_eq!(v.try_gather([v.index_to(1), v.index_to(4)]), None);
+        assert!(v.gather([]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_out_of_bounds()
+    {
+        let v = ['a', 'b'];
+        v.gather([v.index_to(2)]);
+    }
+
+    #[test]
+    fn identity_indices()
+    {
+        let v = ['a', 'b', 'c'];
+        let indices = v.identity_indices();
+
+        assert_eq!(indices.len(), v.len());
+        for (i, idx) in indices.iter().enumerate() { assert_eq!(idx.index(), i); }
+        assert_eq!(v.gather(indices), [&'a', &'b', &'c']);
+
+        let empty : Vec<char> = Vec::new();
+        assert!(empty.identity_indices().is_empty());
+    }
+
+    #[test]
+    fn from_end()
+    {
+        let v = vec!['a', 'b', 'c'];
+        assert_eq!(v.from_end(0), Some(v.index_to(2)));
+        assert_eq!(v[v.from_end(0).unwrap()], 'c');
+        assert_eq!(v.from_end(v.len() - 1), Some(v.index_to(0)));
+        assert_eq!(v.from_end(v.len()), None);
+
+        let empty : [char; 0] = [];
+        assert_eq!(empty.from_end(0), None);
+    }
+}
@@ -1,5 +1,9 @@
 use crate::*;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+use std::str::FromStr;
 
+// There is no `Index` impl for reference to collection (`&Vec<T>`, `&[T]`...), see the [IndexLike] doc.
 
 impl<T> Index<IndexTo<T>> for Vec<T>
 {
@@ -32,4 +36,166 @@ impl Index<IndexTo<u8>> for str
     type Output=u8;
     #[inline]
     fn index(&self, index: IndexTo<u8>) -> &Self::Output { self.as_bytes().index(index.index) }
-}
\ No newline at end of file
+}
+
+impl Index<IndexTo<u8>> for String
+{
+    type Output=u8;
+    #[inline]
+    fn index(&self, index: IndexTo<u8>) -> &Self::Output { self.as_str().index(index) }
+}
+
+impl<Data : ?Sized> TryFrom<IndexTo<Data, usize>> for IndexTo<Data, u32>
+{
+    type Error = std::num::TryFromIntError;
+    #[inline]
+    fn try_from(value: IndexTo<Data, usize>) -> Result<Self, Self::Error> { Ok(Self::from_index(value.index.try_into()?)) }
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl<Data : ?Sized> From<IndexTo<Data, u32>> for IndexTo<Data, usize>
+{
+    #[inline]
+    fn from(value: IndexTo<Data, u32>) -> Self { Self::from_index(value.index as usize) }
+}
+
+
+/// Index a map by a key typed with the value type : `IndexTo<V, K>`.
+///
+/// # Panics
+///
+/// Panics if the key is not present in the `HashMap`.
+impl<K, V, S> Index<IndexTo<V, K>> for HashMap<K, V, S> where K : Eq + Hash, S : BuildHasher
+{
+    type Output=V;
+    #[inline]
+    fn index(&self, index: IndexTo<V, K>) -> &Self::Output { self.index(&index.index) }
+}
+
+/// Index a map by a key typed with the value type : `IndexTo<V, K>`.
+///
+/// # Panics
+///
+/// Panics if the key is not present in the `BTreeMap`.
+impl<K, V> Index<IndexTo<V, K>> for BTreeMap<K, V> where K : Ord
+{
+    type Output=V;
+    #[inline]
+    fn index(&self, index: IndexTo<V, K>) -> &Self::Output { self.index(&index.index) }
+}
+
+impl<Data : ?Sized, Idx> FromStr for IndexTo<Data, Idx> where Idx : FromStr
+{
+    type Err = Idx::Err;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> { Ok(Self::from_index(s.parse()?)) }
+}
+
+/// Convert the slice marker form to the element marker form, keeping the same index.
+impl<T, Idx> From<IndexTo<[T], Idx>> for IndexTo<T, Idx>
+{
+    #[inline]
+    fn from(value: IndexTo<[T], Idx>) -> Self { Self::from_index(value.index) }
+}
+
+/// Convert the element marker form to the slice marker form, keeping the same index.
+impl<T, Idx> From<IndexTo<T, Idx>> for IndexTo<[T], Idx>
+{
+    #[inline]
+    fn from(value: IndexTo<T, Idx>) -> Self { Self::from_index(value.index) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    struct Node;
+
+    fn get_at<C>(collection : &C, index : IndexTo<i32>) -> i32 where C : Index<IndexTo<i32>, Output = i32> + ?Sized { collection[index] }
+
+    #[test]
+    fn index_through_reference()
+    {
+        let v = vec![1, 2, 3];
+        let idx = v.index_to(1);
+
+        let v_ref = &v;
+        assert_eq!(get_at(v_ref, idx), 2);
+        assert_eq!(get_at(v.as_slice(), idx), 2);
+        assert_eq!(v_ref[idx], 2);
+        assert_eq!(idx.get(v_ref), &2);
+    }
+
+    #[test]
+    fn slice_marker_conversion()
+    {
+        let v = vec![10, 20, 30];
+
+        let element : IndexTo<i32> = v.index_to(1);
+        let slice : IndexTo<[i32]> = element.into();
+        assert_eq!(slice.index(), 1);
+
+        let back : IndexTo<i32> = slice.into();
+        assert_eq!(back, element);
+        assert_eq!(v[back], v[element]);
+    }
+
+    #[test]
+    fn index_hash_map_by_key()
+    {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        assert_eq!(map[IndexTo::from_index("b")], 2);
+        assert_eq!(map[&"a"], 1);
+    }
+
+    #[test]
+    fn index_btree_map_by_key()
+    {
+        let mut map = BTreeMap::new();
+        map.insert(3u32, "c");
+        map.insert(1u32, "a");
+
+        assert_eq!(map[IndexTo::from_index(3)], "c");
+        assert_eq!(map[&1], "a");
+    }
+
+    #[test]
+    fn from_str()
+    {
+        struct Thing;
+
+        let idx : IndexTo<Thing, u32> = "42".parse().unwrap();
+        assert_eq!(idx.index(), 42);
+
+        let err = "abc".parse::<IndexTo<Thing, u32>>().unwrap_err();
+        assert_eq!(err, "abc".parse::<u32>().unwrap_err());
+    }
+
+    #[test]
+    fn index_width_conversion()
+    {
+        let idx = IndexTo::<Node>::from_index(42);
+
+        let small : IndexTo<Node, u32> = idx.try_into().unwrap();
+        assert_eq!(small.index(), 42);
+
+        let back : IndexTo<Node> = small.into();
+        assert_eq!(back, idx);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn index_width_conversion_overflow()
+    {
+        let idx = IndexTo::<Node>::from_index(u32::MAX as usize + 1);
+        assert!(IndexTo::<Node, u32>::try_from(idx).is_err());
+
+        let max = IndexTo::<Node>::from_index(u32::MAX as usize);
+        assert_eq!(IndexTo::<Node, u32>::try_from(max).unwrap().index(), u32::MAX);
+    }
+}
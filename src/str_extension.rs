@@ -0,0 +1,55 @@
+use crate::*;
+
+/// Extension methods for `str` (and `String` through deref) that work with strongly typed index.
+pub trait TypedStr
+{
+    /// Replace the byte at `index` by `byte`.
+    ///
+    /// To keep the string valid UTF-8, only an ASCII byte can be replaced, and only by another ASCII byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds, or if the replaced byte or `byte` is not ASCII.
+    fn set_byte(&mut self, index : IndexTo<u8>, byte : u8);
+}
+
+impl TypedStr for str
+{
+    fn set_byte(&mut self, index : IndexTo<u8>, byte : u8)
+    {
+        let old = self.as_bytes()[index.index()];
+        assert!(old.is_ascii() && byte.is_ascii(), "can only replace an ASCII byte by another ASCII byte");
+        // SAFETY: replacing an ASCII byte by another ASCII byte keep the string valid UTF-8
+        unsafe { self.as_bytes_mut()[index.index()] = byte; }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn index_string()
+    {
+        let s = String::from("abc");
+        assert_eq!(s[IndexTo::<u8>::from_index(1)], b'b');
+        assert_eq!(s.as_str()[IndexTo::<u8>::from_index(2)], b'c');
+    }
+
+    #[test]
+    fn set_byte()
+    {
+        let mut s = String::from("abc");
+        s.set_byte(IndexTo::from_index(1), b'x');
+        assert_eq!(s, "axc");
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_byte_non_ascii()
+    {
+        let mut s = String::from("abc");
+        s.set_byte(IndexTo::from_index(0), 0xFF);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::*;
+use std::ops::Deref;
+
+/// A `Vec` that hand out its own strongly typed index.
+///
+/// [TypedVec::push] return a [TypedVecIndex] of the inserted element,
+/// and indexing the `TypedVec` is only possible with a [TypedVecIndex] of the same `Tag`.
+/// An [`IndexTo<T>`] minted by any other collection is rejected at compile time :
+///
+/// ```compile_fail
+/// use typed_index::*;
+///
+/// let mut tv = TypedVec::new();
+/// tv.push(1);
+///
+/// let other = vec![2];
+/// tv[other.index_to(0)] = 9;
+/// ```
+///
+/// Two `TypedVec` with the same `Tag` share the same index type.
+/// Use a different `Tag` for each collection to also reject each other index :
+///
+/// ```compile_fail
+/// use typed_index::*;
+///
+/// struct Enemies;
+/// struct Allies;
+///
+/// let mut enemies = TypedVec::<&str, Enemies>::default();
+/// let mut allies = TypedVec::<&str, Allies>::default();
+///
+/// let goblin = enemies.push("goblin");
+/// allies.push("knight");
+/// allies[goblin] = "traitor";
+/// ```
+///
+/// Read only access to the elements is still possible through the deref to `&[T]`.
+pub struct TypedVec<T, Tag=T>
+    where
+    Tag : ?Sized,
+{
+    values : Vec<T>,
+    tag : PhantomData<fn() -> Tag>,
+}
+
+/// A strongly typed index only handed out by a [TypedVec] with the same `Tag`.
+pub struct TypedVecIndex<Tag>
+    where
+    Tag : ?Sized,
+{
+    index : IndexTo<Tag>,
+}
+
+impl<Tag> TypedVecIndex<Tag>
+    where
+    Tag : ?Sized,
+{
+    #[inline]
+    const fn from_index(index : usize) -> Self { Self { index : IndexTo::from_index(index) } }
+
+    #[inline]
+    pub const fn index(self) -> usize { self.index.index }
+}
+
+impl<T> TypedVec<T>
+{
+    /// Use [TypedVec::default] for a custom `Tag`.
+    #[inline]
+    pub const fn new() -> Self { Self { values : Vec::new(), tag : PhantomData } }
+    #[inline]
+    pub fn with_capacity(capacity : usize) -> Self { Self { values : Vec::with_capacity(capacity), tag : PhantomData } }
+}
+
+impl<T, Tag> TypedVec<T, Tag>
+    where
+    Tag : ?Sized,
+{
+    /// Appends an element to the back of the collection, and return the index of the inserted element.
+    #[inline]
+    pub fn push(&mut self, value : T) -> TypedVecIndex<Tag>
+    {
+        let idx = TypedVecIndex::from_index(self.values.len());
+        self.values.push(value);
+        idx
+    }
+
+    #[inline]
+    pub fn get(&self, index : TypedVecIndex<Tag>) -> Option<&T> { self.values.get(index.index()) }
+    #[inline]
+    pub fn get_mut(&mut self, index : TypedVecIndex<Tag>) -> Option<&mut T> { self.values.get_mut(index.index()) }
+
+    /// Iterate over the elements with their strongly typed index.
+    #[inline]
+    pub fn iter_typed(&self) -> impl Iterator<Item = (TypedVecIndex<Tag>, &T)> { self.values.iter().enumerate().map(|(i, v)| (TypedVecIndex::from_index(i), v)) }
+
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> { self.values }
+}
+
+impl<T, Tag : ?Sized> Default  for TypedVec<T, Tag>                  { #[inline] fn default() -> Self { Self { values : Vec::new(), tag : PhantomData } } }
+impl<T, Tag : ?Sized> Clone    for TypedVec<T, Tag> where T : Clone  { #[inline] fn clone(&self) -> Self { Self { values : self.values.clone(), tag : PhantomData } } }
+impl<T, Tag : ?Sized> Debug    for TypedVec<T, Tag> where T : Debug  { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { self.values.fmt(f) } }
+impl<T, Tag : ?Sized> Deref    for TypedVec<T, Tag>                  { type Target=[T]; #[inline] fn deref(&self) -> &Self::Target { &self.values } }
+impl<T, Tag : ?Sized> From<Vec<T>> for TypedVec<T, Tag>              { #[inline] fn from(values : Vec<T>) -> Self { Self { values, tag : PhantomData } } }
+
+impl<T, Tag : ?Sized> Index<TypedVecIndex<Tag>> for TypedVec<T, Tag>
+{
+    type Output=T;
+    #[inline]
+    fn index(&self, index: TypedVecIndex<Tag>) -> &Self::Output { self.values.index(index.index()) }
+}
+
+impl<T, Tag : ?Sized> IndexMut<TypedVecIndex<Tag>> for TypedVec<T, Tag>
+{
+    #[inline]
+    fn index_mut(&mut self, index: TypedVecIndex<Tag>) -> &mut Self::Output { self.values.index_mut(index.index()) }
+}
+
+impl<Tag : ?Sized> Hash       for TypedVecIndex<Tag> { #[inline] fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.index.hash(state); } }
+impl<Tag : ?Sized> Clone      for TypedVecIndex<Tag> { #[inline] fn clone(&self) -> Self { *self } }
+impl<Tag : ?Sized> Copy       for TypedVecIndex<Tag> {}
+impl<Tag : ?Sized> Debug      for TypedVecIndex<Tag> { fn fmt(&self, f: &mut Formatter<'_>) -> DResult { self.index.fmt(f) } }
+impl<Tag : ?Sized> Eq         for TypedVecIndex<Tag> {}
+impl<Tag : ?Sized> PartialEq  for TypedVecIndex<Tag> { #[inline] fn eq(&self, other: &Self) -> bool { self.index == other.index } }
+impl<Tag : ?Sized> Ord        for TypedVecIndex<Tag> { #[inline] fn cmp(&self, other: &Self) -> std::cmp::Ordering { self.index.cmp(&other.index) } }
+impl<Tag : ?Sized> PartialOrd for TypedVecIndex<Tag> { #[inline] fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) } }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn push_return_a_usable_index()
+    {
+        let mut v = TypedVec::new();
+        let a = v.push("a");
+        let b = v.push("b");
+
+        assert_eq!(v[a], "a");
+        assert_eq!(v[b], "b");
+
+        v[b] = "c";
+        assert_eq!(v.get(b), Some(&"c"));
+        assert_eq!(v.len(), 2);
+        assert_eq!(b.index(), 1);
+    }
+
+    #[test]
+    fn iter_typed()
+    {
+        let mut v = TypedVec::new();
+        let indices = [v.push(10), v.push(20), v.push(30)];
+
+        for (expected, (idx, value)) in indices.iter().zip(v.iter_typed())
+        {
+            assert_eq!(*expected, idx);
+            assert_eq!(v[idx], *value);
+        }
+        assert_eq!(v.iter_typed().count(), 3);
+    }
+
+    #[test]
+    fn tagged()
+    {
+        struct Enemies;
+
+        let mut enemies = TypedVec::<&str, Enemies>::default();
+        let goblin = enemies.push("goblin");
+        assert_eq!(enemies[goblin], "goblin");
+    }
+}
@@ -0,0 +1,204 @@
+use crate::*;
+
+/// Extension methods for `Vec` that work with strongly typed index.
+pub trait TypedVecExtension<T>
+{
+    /// Appends all the elements of the iterator, and return the typed index of the appended elements.
+    fn extend_typed<I>(&mut self, iter : I) -> Vec<IndexTo<T>> where I : IntoIterator<Item = T>;
+
+    /// Like [Vec::remove]. Every index after `index` is shifted, see [remap_after_remove].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn remove_typed(&mut self, index : IndexTo<T>) -> T;
+    /// Like [Vec::swap_remove]. The last index now point to `index`, see [remap_after_remove].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn swap_remove_typed(&mut self, index : IndexTo<T>) -> T;
+
+    /// Like [Vec::dedup], but return a table that map each original index to the index of its surviving element.
+    ///
+    /// A removed element map to the new index of the element it duplicated.
+    fn dedup_with_remap(&mut self) -> Vec<IndexTo<T>> where T : PartialEq;
+}
+
+/// Where the index `other` moved after `removed` was removed from a collection of length `len` (the length before the removal).
+///
+/// Return `None` if `other` is the removed element.
+///
+/// With `was_swap_remove`, the last element moved to `removed` ([Vec::swap_remove]),
+/// otherwise every element after `removed` was shifted by one ([Vec::remove]).
+pub fn remap_after_remove<T>(removed : IndexTo<T>, other : IndexTo<T>, was_swap_remove : bool, len : usize) -> Option<IndexTo<T>>
+    where
+    T : ?Sized,
+{
+    if other == removed { return None; }
+
+    let moved = if was_swap_remove
+    {
+        if other.index + 1 == len { removed } else { other }
+    } else if other > removed { IndexTo::from_index(other.index - 1) } else { other };
+    Some(moved)
+}
+
+impl<T> TypedVecExtension<T> for Vec<T>
+{
+    fn extend_typed<I>(&mut self, iter : I) -> Vec<IndexTo<T>> where I : IntoIterator<Item = T>
+    {
+        let start = self.len();
+        self.extend(iter);
+        (start..self.len()).map(IndexTo::from_index).collect()
+    }
+
+    #[inline]
+    fn remove_typed(&mut self, index : IndexTo<T>) -> T { self.remove(index.index()) }
+    #[inline]
+    fn swap_remove_typed(&mut self, index : IndexTo<T>) -> T { self.swap_remove(index.index()) }
+
+    fn dedup_with_remap(&mut self) -> Vec<IndexTo<T>> where T : PartialEq
+    {
+        let mut remap = Vec::with_capacity(self.len());
+        let mut last_kept = 0;
+        let mut nb_kept = 0;
+
+        for i in 0..self.len()
+        {
+            // same comparison as `Vec::dedup` : against the last kept element
+            if i == 0 || self[i] != self[last_kept]
+            {
+                last_kept = i;
+                nb_kept += 1;
+            }
+            remap.push(IndexTo::from_index(nb_kept - 1));
+        }
+
+        self.dedup();
+        remap
+    }
+}
+
+
+/// Extension methods for `Vec<Option<T>>` used as a sparse storage indexed by strongly typed index.
+pub trait TypedSparseVecExtension<T>
+{
+    /// Return the value at `index`, inserting the one returned by `f` if the slot is empty.
+    ///
+    /// The `Vec` is grown with `None` if `index` is out of bounds.
+    fn get_or_insert_typed<F>(&mut self, index : IndexTo<T>, f : F) -> &mut T where F : FnOnce() -> T;
+}
+
+impl<T> TypedSparseVecExtension<T> for Vec<Option<T>>
+{
+    fn get_or_insert_typed<F>(&mut self, index : IndexTo<T>, f : F) -> &mut T where F : FnOnce() -> T
+    {
+        let index = index.index();
+        if index >= self.len() { self.resize_with(index + 1, || None); }
+        self[index].get_or_insert_with(f)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn extend_typed()
+    {
+        let mut v = vec![1, 2];
+        let indices = v.extend_typed([3, 4, 5]);
+
+        assert_eq!(indices, [v.index_to(2), v.index_to(3), v.index_to(4)]);
+        assert_eq!(indices.iter().map(|i| v[*i]).collect::<Vec<_>>(), [3, 4, 5]);
+    }
+
+    #[test]
+    fn extend_typed_empty()
+    {
+        let mut v = vec![1, 2];
+        assert!(v.extend_typed([]).is_empty());
+        assert_eq!(v, [1, 2]);
+    }
+
+    #[test]
+    fn remove_remap()
+    {
+        let mut v = vec!['a', 'b', 'c', 'd'];
+        let len = v.len();
+        let removed = v.index_to(1);
+        let indices : Vec<_> = (0..len).map(|i| v.index_to(i)).collect();
+
+        assert_eq!(v.remove_typed(removed), 'b');
+
+        let remapped : Vec<_> = indices.iter().map(|i| remap_after_remove(removed, *i, false, len)).collect();
+        assert_eq!(remapped, [Some(v.index_to(0)), None, Some(v.index_to(1)), Some(v.index_to(2))]);
+        assert_eq!(remapped.iter().flatten().map(|i| v[*i]).collect::<String>(), "acd");
+    }
+
+    #[test]
+    fn swap_remove_remap()
+    {
+        let mut v = vec!['a', 'b', 'c', 'd'];
+        let len = v.len();
+        let removed = v.index_to(1);
+        let indices : Vec<_> = (0..len).map(|i| v.index_to(i)).collect();
+
+        assert_eq!(v.swap_remove_typed(removed), 'b');
+
+        let remapped : Vec<_> = indices.iter().map(|i| remap_after_remove(removed, *i, true, len)).collect();
+        assert_eq!(remapped, [Some(v.index_to(0)), None, Some(v.index_to(2)), Some(v.index_to(1))]);
+        assert_eq!(remapped.iter().flatten().map(|i| v[*i]).collect::<String>(), "acd");
+    }
+
+    #[test]
+    fn swap_remove_last_remap()
+    {
+        let mut v = vec!['a', 'b'];
+        let removed = v.index_to(1);
+        v.swap_remove_typed(removed);
+
+        assert_eq!(remap_after_remove(removed, removed, true, 2), None);
+        assert_eq!(remap_after_remove(removed, v.index_to(0), true, 2), Some(v.index_to(0)));
+    }
+
+    #[test]
+    fn dedup_with_remap()
+    {
+        let original = vec!['a', 'a', 'b', 'c', 'c', 'c', 'a'];
+        let mut v = original.clone();
+
+        let remap = v.dedup_with_remap();
+        assert_eq!(v, ['a', 'b', 'c', 'a']);
+        assert_eq!(remap.iter().map(|i| i.index()).collect::<Vec<_>>(), [0, 0, 1, 2, 2, 2, 3]);
+
+        for (old, new) in remap.iter().enumerate()
+        {
+            assert_eq!(original[old], v[*new]);
+        }
+    }
+
+    #[test]
+    fn dedup_with_remap_empty()
+    {
+        let mut v : Vec<i32> = Vec::new();
+        assert!(v.dedup_with_remap().is_empty());
+    }
+
+    #[test]
+    fn get_or_insert_typed()
+    {
+        let mut v : Vec<Option<&str>> = Vec::new();
+
+        assert_eq!(*v.get_or_insert_typed(IndexTo::from_index(0), || "a"), "a");
+        assert_eq!(v, [Some("a")]);
+
+        assert_eq!(*v.get_or_insert_typed(IndexTo::from_index(3), || "d"), "d");
+        assert_eq!(v, [Some("a"), None, None, Some("d")]);
+
+        *v.get_or_insert_typed(IndexTo::from_index(3), || unreachable!()) = "e";
+        assert_eq!(v, [Some("a"), None, None, Some("e")]);
+    }
+}
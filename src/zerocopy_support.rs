@@ -0,0 +1,26 @@
+// `FromZeroes`, `FromBytes`, `AsBytes` and `Unaligned` are derived on `IndexTo` when the "zerocopy" feature is enabled.
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use zerocopy::{AsBytes, FromBytes};
+
+    struct Node;
+
+    #[test]
+    fn from_bytes()
+    {
+        let bytes : [u8; 4] = 42u32.to_ne_bytes();
+
+        let idx = IndexTo::<Node, u32>::read_from(&bytes[..]).unwrap();
+        assert_eq!(idx.index(), 42);
+        assert_eq!(idx.as_bytes(), bytes);
+
+        let indices = IndexTo::<Node, u8>::slice_from(&[1, 2, 3][..]).unwrap();
+        assert_eq!(IndexTo::as_slice_of_indices(indices), [1, 2, 3]);
+
+        // The derived impls don't require `Data` to be sized
+        let idx = IndexTo::<[Node], u32>::read_from(&bytes[..]).unwrap();
+        assert_eq!(idx.index(), 42);
+    }
+}